@@ -0,0 +1,206 @@
+use helpers::{AocError, Solution};
+use walker::{FloorWalker, ParsePolicy};
+
+pub mod walker;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+
+    type Input = FloorWalker;
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> std::result::Result<Self::Input, AocError> {
+        let mut walker = FloorWalker::with_policy(ParsePolicy::SkipWhitespace);
+        walker.walk(input.as_bytes()).map_err(AocError::parse)?;
+        Ok(walker)
+    }
+
+    fn part1(walker: &Self::Input) -> std::result::Result<Self::Answer1, AocError> {
+        Ok(walker.floor())
+    }
+
+    fn part2(walker: &Self::Input) -> std::result::Result<Self::Answer2, AocError> {
+        walker
+            .first_visit(-1)
+            .ok_or_else(|| AocError::compute("entrance not found"))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// Character at 0-based byte `offset` is not a direction, nor skipped by the [`ParsePolicy`].
+    /// `found` is `U+FFFD` if the bytes there are not valid UTF-8.
+    InvalidDirection { offset: usize, found: char },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidDirection { offset, found } => write!(
+                f,
+                "invalid direction '{}' at byte {offset}, expected '(' or ')'",
+                found.escape_debug()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+pub fn count_floor(input: &str) -> Result<i32> {
+    let mut walker = FloorWalker::new();
+    walker.walk(input.as_bytes())?;
+    Ok(walker.floor())
+}
+
+/// Position at which `wanted_floor` is reached first. Ground floor is reached at position 0.
+pub fn entrance_pos(input: &str, wanted_floor: i32) -> Result<Option<usize>> {
+    let mut walker = FloorWalker::new();
+    walker.walk(input.as_bytes())?;
+    Ok(walker.first_visit(wanted_floor))
+}
+
+#[cfg(test)]
+mod count_floor {
+    use super::*;
+
+    #[test]
+    fn count_floor_1() {
+        assert_eq!(count_floor("(())"), Ok(0));
+    }
+
+    #[test]
+    fn count_floor_2() {
+        assert_eq!(count_floor("()()"), Ok(0));
+    }
+
+    #[test]
+    fn count_floor_3() {
+        assert_eq!(count_floor("((("), Ok(3));
+    }
+
+    #[test]
+    fn count_floor_4() {
+        assert_eq!(count_floor("(()(()("), Ok(3));
+    }
+
+    #[test]
+    fn count_floor_5() {
+        assert_eq!(count_floor("))((((("), Ok(3));
+    }
+
+    #[test]
+    fn count_floor_6() {
+        assert_eq!(count_floor("())"), Ok(-1));
+    }
+
+    #[test]
+    fn count_floor_7() {
+        assert_eq!(count_floor("))("), Ok(-1));
+    }
+
+    #[test]
+    fn count_floor_8() {
+        assert_eq!(count_floor(")))"), Ok(-3));
+    }
+
+    #[test]
+    fn count_floor_9() {
+        assert_eq!(count_floor(")())())"), Ok(-3));
+    }
+
+    #[test]
+    fn count_floor_invalid_1() {
+        assert_eq!(
+            count_floor("()1"),
+            Err(Error::InvalidDirection {
+                offset: 2,
+                found: '1'
+            })
+        );
+    }
+
+    #[test]
+    fn count_floor_invalid_newline() {
+        let err = count_floor("(\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid direction '\\n' at byte 1, expected '(' or ')'"
+        );
+    }
+
+    #[test]
+    fn count_floor_invalid_multibyte() {
+        let err = count_floor("(é)").unwrap_err();
+        assert_eq!(
+            err,
+            Error::InvalidDirection {
+                offset: 1,
+                found: 'é'
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "invalid direction 'é' at byte 1, expected '(' or ')'"
+        );
+    }
+}
+
+#[cfg(test)]
+mod entrance_pos {
+    use super::{Error, Result};
+
+    pub fn entrance_pos(input: &str) -> Result<Option<usize>> {
+        super::entrance_pos(input, -1)
+    }
+
+    #[test]
+    fn entrance_pos_1() {
+        assert_eq!(entrance_pos(")"), Ok(Some(1)));
+    }
+
+    #[test]
+    fn entrance_pos_2() {
+        assert_eq!(entrance_pos("()())"), Ok(Some(5)));
+    }
+
+    #[test]
+    fn entrance_pos_no_entrance_1() {
+        assert_eq!(entrance_pos("()()"), Ok(None));
+    }
+
+    #[test]
+    fn entrance_pos_invalid_1() {
+        assert_eq!(
+            entrance_pos(")1"),
+            Err(Error::InvalidDirection {
+                offset: 1,
+                found: '1'
+            })
+        );
+    }
+
+    #[test]
+    fn entrance_pos_invalid_2() {
+        assert_eq!(
+            entrance_pos("1)"),
+            Err(Error::InvalidDirection {
+                offset: 0,
+                found: '1'
+            })
+        );
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+
+    helpers::example_tests!(Day1; balanced, basement_first, basement_fifth);
+}
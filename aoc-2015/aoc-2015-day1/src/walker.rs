@@ -0,0 +1,297 @@
+use std::{
+    collections::BTreeMap,
+    io::{self, BufRead, BufReader, Read},
+};
+
+use crate::{Error, Result};
+
+/// What to do with bytes that are not directions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ParsePolicy {
+    /// Anything but `(` and `)` is an error.
+    #[default]
+    Strict,
+    /// ASCII whitespace, e.g. the trailing newline of a saved input, is skipped.
+    SkipWhitespace,
+    /// Anything but `(` and `)` is skipped.
+    SkipUnknown,
+}
+
+impl ParsePolicy {
+    fn skips(self, byte: u8) -> bool {
+        match self {
+            ParsePolicy::Strict => false,
+            ParsePolicy::SkipWhitespace => byte.is_ascii_whitespace(),
+            ParsePolicy::SkipUnknown => true,
+        }
+    }
+}
+
+/// Follows Santa's directions one byte at a time, keeping the history of floors.
+///
+/// Positions are 1-based, as in the puzzle: position 1 is the first direction.
+/// Santa starts at floor 0 at position 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FloorWalker {
+    policy: ParsePolicy,
+    /// Number of bytes seen, skipped ones included.
+    offset: usize,
+    floor: i32,
+    position: usize,
+    min: i32,
+    max: i32,
+    first_visits: BTreeMap<i32, usize>,
+    visits: BTreeMap<i32, usize>,
+}
+
+impl Default for FloorWalker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FloorWalker {
+    /// Walker with the [`ParsePolicy::Strict`] policy.
+    pub fn new() -> Self {
+        Self::with_policy(ParsePolicy::Strict)
+    }
+
+    pub fn with_policy(policy: ParsePolicy) -> Self {
+        Self {
+            policy,
+            offset: 0,
+            floor: 0,
+            position: 0,
+            min: 0,
+            max: 0,
+            first_visits: BTreeMap::from([(0, 0)]),
+            visits: BTreeMap::from([(0, 1)]),
+        }
+    }
+
+    /// Walks `(` up or `)` down a floor. Other bytes are skipped or rejected by the policy.
+    ///
+    /// A single byte can't tell a multibyte character, so a rejected non-ASCII byte
+    /// is reported as `U+FFFD`. [`walk`](Self::walk) reports the character instead.
+    pub fn step(&mut self, direction: u8) -> Result<()> {
+        let offset = self.offset;
+        self.offset += 1;
+        match direction {
+            b'(' => self.floor += 1,
+            b')' => self.floor -= 1,
+            _ if self.policy.skips(direction) => return Ok(()),
+            _ => {
+                return Err(Error::InvalidDirection {
+                    offset,
+                    found: char_at(&[direction]),
+                })
+            }
+        }
+        self.position += 1;
+        self.min = self.min.min(self.floor);
+        self.max = self.max.max(self.floor);
+        self.first_visits.entry(self.floor).or_insert(self.position);
+        *self.visits.entry(self.floor).or_default() += 1;
+        Ok(())
+    }
+
+    pub fn walk(&mut self, directions: &[u8]) -> Result<()> {
+        let start = self.offset;
+        directions
+            .iter()
+            .try_for_each(|&direction| self.step(direction))
+            .map_err(
+                |Error::InvalidDirection { offset, .. }| Error::InvalidDirection {
+                    offset,
+                    found: char_at(&directions[offset - start..]),
+                },
+            )
+    }
+
+    /// Walks directions streamed from `reader`, without reading them whole.
+    ///
+    /// An invalid direction is reported as [`io::ErrorKind::InvalidData`]
+    /// wrapping the [`Error`]. A character split between reads is reported as `U+FFFD`.
+    pub fn walk_reader(&mut self, reader: impl Read) -> io::Result<()> {
+        let mut reader = BufReader::new(reader);
+        loop {
+            let buf = reader.fill_buf()?;
+            if buf.is_empty() {
+                return Ok(());
+            }
+            let len = buf.len();
+            self.walk(buf)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            reader.consume(len);
+        }
+    }
+
+    pub fn floor(&self) -> i32 {
+        self.floor
+    }
+
+    /// Number of directions walked. Skipped bytes are not counted.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn min_floor(&self) -> i32 {
+        self.min
+    }
+
+    pub fn max_floor(&self) -> i32 {
+        self.max
+    }
+
+    /// Position at which `floor` was reached first.
+    pub fn first_visit(&self, floor: i32) -> Option<usize> {
+        self.first_visits.get(&floor).copied()
+    }
+
+    /// First-visit position of every floor reached.
+    pub fn first_visits(&self) -> &BTreeMap<i32, usize> {
+        &self.first_visits
+    }
+
+    /// How many times Santa has been at `floor`, the start included.
+    pub fn visits(&self, floor: i32) -> usize {
+        self.visits.get(&floor).copied().unwrap_or_default()
+    }
+
+    /// Visit counts of every floor reached.
+    pub fn histogram(&self) -> &BTreeMap<i32, usize> {
+        &self.visits
+    }
+}
+
+/// First character of `bytes`, `U+FFFD` if they don't start with valid UTF-8.
+fn char_at(bytes: &[u8]) -> char {
+    let bytes = &bytes[..bytes.len().min(4)];
+    String::from_utf8_lossy(bytes)
+        .chars()
+        .next()
+        .unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn walked(directions: &str) -> FloorWalker {
+        let mut walker = FloorWalker::new();
+        walker.walk(directions.as_bytes()).unwrap();
+        walker
+    }
+
+    #[test]
+    fn starts_at_ground_floor() {
+        let walker = FloorWalker::new();
+        assert_eq!(walker.floor(), 0);
+        assert_eq!(walker.first_visit(0), Some(0));
+        assert_eq!(walker.visits(0), 1);
+    }
+
+    #[test]
+    fn history() {
+        let walker = walked("(()))(");
+        assert_eq!(walker.floor(), 0);
+        assert_eq!(walker.position(), 6);
+        assert_eq!((walker.min_floor(), walker.max_floor()), (-1, 2));
+        assert_eq!(
+            walker.first_visits(),
+            &BTreeMap::from([(-1, 5), (0, 0), (1, 1), (2, 2)])
+        );
+        assert_eq!(
+            walker.histogram(),
+            &BTreeMap::from([(-1, 1), (0, 3), (1, 2), (2, 1)])
+        );
+        assert_eq!(walker.visits(3), 0);
+    }
+
+    #[test]
+    fn invalid_direction() {
+        let mut walker = FloorWalker::new();
+        assert_eq!(
+            walker.walk("(x(".as_bytes()),
+            Err(Error::InvalidDirection {
+                offset: 1,
+                found: 'x'
+            })
+        );
+        assert_eq!(walker.floor(), 1);
+    }
+
+    #[test]
+    fn invalid_multibyte_direction() {
+        let mut walker = FloorWalker::new();
+        assert_eq!(
+            walker.walk("(é".as_bytes()),
+            Err(Error::InvalidDirection {
+                offset: 1,
+                found: 'é'
+            })
+        );
+        assert_eq!(
+            FloorWalker::new().step("é".as_bytes()[0]),
+            Err(Error::InvalidDirection {
+                offset: 0,
+                found: char::REPLACEMENT_CHARACTER
+            })
+        );
+        assert_eq!(
+            FloorWalker::new().walk(b"(\xff"),
+            Err(Error::InvalidDirection {
+                offset: 1,
+                found: char::REPLACEMENT_CHARACTER
+            })
+        );
+    }
+
+    #[test]
+    fn skip_whitespace() {
+        let mut walker = FloorWalker::with_policy(ParsePolicy::SkipWhitespace);
+        walker.walk("(( )\r\n".as_bytes()).unwrap();
+        assert_eq!((walker.floor(), walker.position()), (1, 3));
+        assert_eq!(walker.first_visit(2), Some(2));
+
+        assert_eq!(
+            walker.walk("(x".as_bytes()),
+            Err(Error::InvalidDirection {
+                offset: 7,
+                found: 'x'
+            })
+        );
+    }
+
+    #[test]
+    fn skip_unknown() {
+        let mut walker = FloorWalker::with_policy(ParsePolicy::SkipUnknown);
+        walker.walk("(1)é)\n".as_bytes()).unwrap();
+        assert_eq!((walker.floor(), walker.position()), (-1, 3));
+        assert_eq!(walker.first_visit(-1), Some(3));
+    }
+
+    #[test]
+    fn walk_reader() {
+        let directions = ")(".repeat(10_000);
+        let mut walker = FloorWalker::new();
+        walker.walk_reader(directions.as_bytes()).unwrap();
+        assert_eq!(walker, walked(&directions));
+        assert_eq!(walker.visits(-1), 10_000);
+    }
+
+    #[test]
+    fn walk_reader_invalid() {
+        let err = FloorWalker::new()
+            .walk_reader("()1".as_bytes())
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.into_inner().unwrap().downcast_ref::<Error>(),
+            Some(&Error::InvalidDirection {
+                offset: 2,
+                found: '1'
+            })
+        );
+    }
+}
@@ -1 +1,39 @@
+use helpers::{AocError, Solution};
+use look_and_say::look_and_say_n_times;
+
 pub mod look_and_say;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 10;
+
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let input = input.trim();
+        if input.is_empty() || !input.chars().all(|c| c.is_ascii_digit()) {
+            return Err(AocError::parse(format!("expected digits, got '{input}'")));
+        }
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, AocError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, AocError> {
+        Ok(part2(input))
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    look_and_say_n_times(input, 40).len()
+}
+
+pub fn part2(input: &str) -> usize {
+    look_and_say_n_times(input, 50).len()
+}
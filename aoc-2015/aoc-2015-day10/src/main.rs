@@ -1,16 +1,10 @@
-use aoc_2015_day10::look_and_say::look_and_say_n_times;
+use aoc_2015_day10::{part1, part2, Day10};
 
-fn main() {
-    run_part1();
-    run_part2();
-}
-
-fn run_part1() {
-    let res = look_and_say_n_times("1113222113", 40);
-    println!("Part 1: {}", res.len());
-}
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let input = helpers::input::from_args::<Day10>()?;
+    let input = input.trim();
 
-fn run_part2() {
-    let res = look_and_say_n_times("1113222113", 50);
-    println!("Part 2: {}", res.len());
+    println!("Part 1: {}", part1(input));
+    println!("Part 2: {}", part2(input));
+    Ok(())
 }
@@ -1 +1,48 @@
+use helpers::{AocError, Solution};
+use password::{security::SecurityElf, Password, PasswordError};
+
 pub mod password;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 11;
+
+    type Input = Password;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input.trim()).map_err(AocError::parse)
+    }
+
+    fn part1(pass: &Self::Input) -> Result<Self::Answer1, AocError> {
+        let next = valid_passwords_after(pass).next();
+        let next = next.ok_or_else(|| AocError::compute("no valid password found"))?;
+        Ok(next.as_str().to_owned())
+    }
+
+    fn part2(pass: &Self::Input) -> Result<Self::Answer2, AocError> {
+        let next = valid_passwords_after(pass).nth(1);
+        let next = next.ok_or_else(|| AocError::compute("no valid password found"))?;
+        Ok(next.as_str().to_owned())
+    }
+}
+
+/// Iterator over passwords, which come after `pass` and are valid for Security-Elf.
+pub fn valid_passwords_after(pass: &Password) -> impl Iterator<Item = Password> {
+    pass.next_password_iter()
+        .filter(|p| SecurityElf::is_valid(p.as_str()))
+}
+
+pub fn parse(pass: &str) -> Result<Password, PasswordError> {
+    Password::new(pass.to_owned())
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+
+    helpers::example_tests!(Day11; abcdefgh);
+}
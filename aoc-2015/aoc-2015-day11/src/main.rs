@@ -1,15 +1,11 @@
-use aoc_2015_day11::password::{security::SecurityElf, Password};
+use aoc_2015_day11::{parse, valid_passwords_after, Day11};
 
-fn main() {
-    run("hxbxwxba");
-}
-
-fn run(pass: &str) {
-    let mut iter = Password::new(pass.to_string())
-        .unwrap()
-        .next_password_iter()
-        .filter(|p| SecurityElf::is_valid(p.as_str()));
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let input = helpers::input::from_args::<Day11>()?;
+    let pass = parse(input.trim())?;
+    let mut iter = valid_passwords_after(&pass);
 
-    println!("Next: {}", iter.next().unwrap().as_str());
-    println!("Next: {}", iter.next().unwrap().as_str());
+    println!("Next: {}", iter.next().ok_or("no next password")?.as_str());
+    println!("Next: {}", iter.next().ok_or("no next password")?.as_str());
+    Ok(())
 }
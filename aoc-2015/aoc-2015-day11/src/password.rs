@@ -27,7 +27,7 @@ impl Password {
     pub fn next(&self) -> Password {
         let chars = self.0.chars().collect::<Vec<char>>();
         let ret = Self::incr(&chars);
-        Password(String::from_iter(ret.into_iter()))
+        Password(String::from_iter(ret))
     }
 
     fn incr(s: &[char]) -> Vec<char> {
@@ -40,7 +40,7 @@ impl Password {
         let (mut ret, last) = match last {
             'z' => (Self::incr(left), 'a'),
             'a'..='z' => (
-                Vec::from_iter(left.into_iter().copied()),
+                Vec::from_iter(left.iter().copied()),
                 // it's safe to cast a..z to u8
                 ((last as u8) + 1) as char,
             ),
@@ -8,7 +8,7 @@ pub fn contains_strait(s: &str, len: usize, order: &Order) -> bool {
     if chars.len() < len {
         return false;
     }
-    chars.windows(len).any(|w| is_ordered(&w, &order))
+    chars.windows(len).any(|w| is_ordered(w, order))
 }
 
 pub fn contains_sequences(s: &str, len: usize, at_least: usize, overlap: bool) -> bool {
@@ -1 +1,61 @@
+use helpers::{AocError, Solution};
+use summarize::JsonSummarizeError;
+
 pub mod summarize;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 12;
+
+    type Input = serde_json::Value;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        serde_json::from_str(input).map_err(|e| {
+            let line = e.line();
+            AocError::parse(e).at_line(line)
+        })
+    }
+
+    fn part1(json: &Self::Input) -> Result<Self::Answer1, AocError> {
+        part1(json).map_err(AocError::compute)
+    }
+
+    fn part2(json: &Self::Input) -> Result<Self::Answer2, AocError> {
+        part2(json).map_err(AocError::compute)
+    }
+}
+
+/// Sum of all numbers in the document.
+pub fn part1(json: &serde_json::Value) -> Result<i64, JsonSummarizeError> {
+    summarize::sum(json)
+}
+
+/// Sum of all numbers in the document, ignoring objects with "red" value.
+pub fn part2(json: &serde_json::Value) -> Result<i64, JsonSummarizeError> {
+    summarize::sum_if(json, is_not_red_obj)
+}
+
+fn is_not_red_obj(v: &serde_json::Value) -> bool {
+    let mut is_red_obj = false;
+    if let serde_json::Value::Object(o) = v {
+        is_red_obj = o.values().any(|v| {
+            if let serde_json::Value::String(s) = v {
+                s == "red"
+            } else {
+                false
+            }
+        });
+    }
+    !is_red_obj
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+
+    helpers::example_tests!(Day12; array, red_object, red_root);
+}
@@ -1 +1,81 @@
+use helpers::{AocError, Solution};
+use itertools::Itertools;
+use relations::{CalculateHappinessError, Relation, RelationMap};
+
 pub mod relations;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 13;
+
+    type Input = RelationMap;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(relation_map: &Self::Input) -> Result<Self::Answer1, AocError> {
+        find_optimal_table_happiness(relation_map).map_err(AocError::compute)
+    }
+
+    fn part2(relation_map: &Self::Input) -> Result<Self::Answer2, AocError> {
+        let mut relation_map = relation_map.clone();
+        seat_myself(&mut relation_map);
+        find_optimal_table_happiness(&relation_map).map_err(AocError::compute)
+    }
+}
+
+pub fn parse(input: &str) -> Result<RelationMap, AocError> {
+    let mut relation_map = RelationMap::new();
+    for (i, line) in input.lines().enumerate() {
+        let relation = Relation::from_adventofcode_line(line)
+            .map_err(|e| AocError::parse(e).at_line(i + 1))?;
+        relation_map.update_relation(relation);
+    }
+    Ok(relation_map)
+}
+
+pub fn find_optimal_table_happiness(
+    relation_map: &RelationMap,
+) -> Result<i32, CalculateHappinessError> {
+    let all = relation_map
+        .participants()
+        .values()
+        .copied()
+        .collect::<Vec<_>>();
+    let len = all.len();
+
+    let mut max: Option<i32> = None;
+    for happiness in all
+        .into_iter()
+        .permutations(len)
+        .unique()
+        .map(|c| relation_map.calculate_happiness(c.as_slice()))
+    {
+        let happiness = happiness?;
+        max = max.map_or(Some(*happiness), |x| Some(x.max(*happiness)));
+    }
+    Ok(max.unwrap_or_default())
+}
+
+pub fn seat_myself(relation_map: &mut RelationMap) {
+    const ME: &str = "Me";
+
+    let all = relation_map.participants().keys().cloned().collect_vec();
+
+    for participant in all {
+        relation_map.update_relation(rel!(participant.as_str(), ME, 0));
+        relation_map.update_relation(rel!(ME, participant.as_str(), 0));
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+
+    helpers::example_tests!(Day13; table);
+}
@@ -23,13 +23,19 @@ pub enum CalculateHappinessError {
     TableHasUnknonParticipant,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct RelationMap {
     next_idx: Idx,
     participants: HashMap<String, Idx>,
     relations: HashMap<Idx, HashMap<Idx, Happiness>>,
 }
 
+impl Default for RelationMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl RelationMap {
     pub fn new() -> Self {
         RelationMap {
@@ -77,7 +83,7 @@ impl RelationMap {
             2 => Ok((*self.relations[&table[0]][&table[1]]
                 + *self.relations[&table[1]][&table[0]])
                 .into()),
-            len @ _ => {
+            len => {
                 let mut happiness = 0;
                 for i in 0..len {
                     let subject = table[i];
@@ -102,7 +108,7 @@ impl RelationMap {
     /// minimum 2 participants.
     pub fn is_correct(&self) -> bool {
         match self.participants.len() {
-            0 => self.relations.len() == 0,
+            0 => self.relations.is_empty(),
             1 => false,
             _ => {
                 let (r, p) = (
@@ -115,7 +121,7 @@ impl RelationMap {
                 self.relations.iter().all(|(to, from)| {
                     let froms = HashSet::<&Idx>::from_iter(from.keys());
                     let mut diff = p.difference(&froms);
-                    let has_to = diff.next() == Some(&&to);
+                    let has_to = diff.next() == Some(&to);
                     has_to && diff.next().is_none()
                 })
             }
@@ -223,7 +229,7 @@ pub mod relation_map {
 
             #[test]
             fn many_participants() {
-                const PARTICIPANTS: &'static str = r#"Alice would gain 54 happiness units by sitting next to Bob.
+                const PARTICIPANTS: &str = r#"Alice would gain 54 happiness units by sitting next to Bob.
                 Alice would lose 79 happiness units by sitting next to Carol.
                 Alice would lose 2 happiness units by sitting next to David.
                 Bob would gain 83 happiness units by sitting next to Alice.
@@ -258,7 +264,7 @@ pub mod relation_map {
             #[test]
             fn zero_participants() {
                 let relation_map = RelationMap::new();
-                assert_eq!(relation_map.is_correct(), true);
+                assert!(relation_map.is_correct());
             }
 
             #[test]
@@ -267,33 +273,33 @@ pub mod relation_map {
                 let mut relation_map = RelationMap::new();
                 relation_map.participants.insert("Bob".to_owned(), Idx(0));
                 relation_map.next_idx = Idx(1);
-                assert_eq!(relation_map.is_correct(), false);
+                assert!(!relation_map.is_correct());
             }
 
             #[test]
             fn two_participants() {
                 let mut relation_map = RelationMap::new();
                 relation_map.update_relation(rel!("Alice", "Bob", 54));
-                assert_eq!(relation_map.is_correct(), false);
+                assert!(!relation_map.is_correct());
                 relation_map.update_relation(rel!("Bob", "Alice", -33));
-                assert_eq!(relation_map.is_correct(), true);
+                assert!(relation_map.is_correct());
             }
 
             #[test]
             fn three_participants() {
                 let mut relation_map = RelationMap::new();
                 relation_map.update_relation(rel!("Alice", "Bob", 54));
-                assert_eq!(relation_map.is_correct(), false);
+                assert!(!relation_map.is_correct());
                 relation_map.update_relation(rel!("Bob", "Alice", -33));
-                assert_eq!(relation_map.is_correct(), true);
+                assert!(relation_map.is_correct());
                 relation_map.update_relation(rel!("Alice", "Fred", 123));
-                assert_eq!(relation_map.is_correct(), false);
+                assert!(!relation_map.is_correct());
                 relation_map.update_relation(rel!("Bob", "Fred", 123));
-                assert_eq!(relation_map.is_correct(), false);
+                assert!(!relation_map.is_correct());
                 relation_map.update_relation(rel!("Fred", "Alice", -333));
-                assert_eq!(relation_map.is_correct(), false);
+                assert!(!relation_map.is_correct());
                 relation_map.update_relation(rel!("Fred", "Bob", -333));
-                assert_eq!(relation_map.is_correct(), true);
+                assert!(relation_map.is_correct());
             }
         }
 
@@ -309,7 +315,7 @@ pub mod relation_map {
                 assert_eq!(relation_map.participants["Alice"], from);
                 assert_eq!(relation_map.participants["Bob"], to);
                 assert_eq!(*relation_map.relations[&to][&from], 54);
-                assert_eq!(relation_map.relations.contains_key(&from), false);
+                assert!(!relation_map.relations.contains_key(&from));
 
                 let (from, to) = relation_map.update_relation(rel!("Bob", "Alice", -33));
                 assert_ne!(from, to);
@@ -347,7 +353,7 @@ pub mod relation {
 
             let (to, happiness_sign, happiness, from) = (0, 2, 3, 10);
             if words.len() != 11 {
-                return Err(IntoRelationError::InvalidLineLength);
+                Err(IntoRelationError::InvalidLineLength)
             } else {
                 let happiness_sign = match words[happiness_sign] {
                     "gain" => 1,
@@ -1,2 +1,72 @@
+use helpers::{parse_lines, AocError, Solution};
+use race::{judge::LeadingReindeerJudge, JudgedRace, NormalRace, Race};
+use reindeer::Reindeer;
+
 pub mod race;
 pub mod reindeer;
+
+pub const RACE_SECS: u32 = 2503;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 14;
+
+    type Input = Vec<Reindeer>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(reindeers: &Self::Input) -> Result<Self::Answer1, AocError> {
+        let (_, score) = winner(NormalRace::new(reindeers), RACE_SECS)
+            .ok_or_else(|| AocError::compute("No winner"))?;
+        Ok(score)
+    }
+
+    fn part2(reindeers: &Self::Input) -> Result<Self::Answer2, AocError> {
+        let race = JudgedRace::new(reindeers, LeadingReindeerJudge::new());
+        let (_, score) = winner(race, RACE_SECS).ok_or_else(|| AocError::compute("No winner"))?;
+        Ok(score)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Reindeer>, AocError> {
+    parse_lines(input, Reindeer::from_aoc_line)
+}
+
+/// Returns name and score of the reindeer, who leads the race after `secs`.
+pub fn winner(race: impl Race, secs: u32) -> Option<(String, u32)> {
+    let race = race.after(secs);
+    let (winner, score) = race
+        .scores()
+        .into_iter()
+        .enumerate()
+        .max_by_key(|(_, d)| *d)?;
+    Some((race.reindeers()[winner].name.clone(), score))
+}
+
+#[cfg(test)]
+mod examples {
+    use helpers::Part;
+
+    use super::*;
+
+    /// Puzzle examples race for 1000 seconds.
+    fn race_1000_secs(input: &str, part: Part) -> u32 {
+        let reindeers = parse(input).unwrap();
+        let winner = match part {
+            Part::One => winner(NormalRace::new(&reindeers), 1000),
+            Part::Two => winner(
+                JudgedRace::new(&reindeers, LeadingReindeerJudge::new()),
+                1000,
+            ),
+        };
+        winner.unwrap().1
+    }
+
+    helpers::example_tests!(2015, 14, race_1000_secs; comet_and_dancer);
+}
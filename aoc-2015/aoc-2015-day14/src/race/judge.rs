@@ -12,6 +12,12 @@ pub struct LeadingReindeerJudge {
     scores: Option<Vec<u32>>,
 }
 
+impl Default for LeadingReindeerJudge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl LeadingReindeerJudge {
     pub fn new() -> Self {
         Self { scores: None }
@@ -1,6 +1,65 @@
 use bitflags::bitflags;
+use helpers::{parse_lines, AocError, Solution};
+use itertools::Itertools;
 use std::str::FromStr;
 
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 15;
+
+    type Input = Vec<Ingredient>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(ingridients: &Self::Input) -> Result<Self::Answer1, AocError> {
+        let ingridients = ingridients.iter().collect::<Vec<_>>();
+        find_best_score(&ingridients).ok_or_else(|| AocError::compute("no cookie found"))
+    }
+
+    fn part2(ingridients: &Self::Input) -> Result<Self::Answer2, AocError> {
+        let ingridients = ingridients.iter().collect::<Vec<_>>();
+        find_best_score_if(&ingridients, |cookie| cookie.calories() == 500)
+            .ok_or_else(|| AocError::compute("no cookie found"))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Ingredient>, AocError> {
+    parse_lines(input, str::parse)
+}
+
+pub fn find_best_score_if(
+    ingridients: &[&Ingredient],
+    predicate: impl Fn(&Cookie) -> bool,
+) -> Option<u32> {
+    (0..ingridients.len())
+        .combinations_with_replacement(100)
+        .filter_map(|c| {
+            let ingridients = c
+                .into_iter()
+                .counts()
+                .into_iter()
+                .map(|c| (ingridients[c.0], c.1 as u32))
+                .collect::<Vec<_>>();
+            let cookie = Cookie::new(ingridients);
+            if predicate(&cookie) {
+                Some(cookie.score_without_calories())
+            } else {
+                None
+            }
+        })
+        .max()
+}
+
+pub fn find_best_score(ingridients: &[&Ingredient]) -> Option<u32> {
+    find_best_score_if(ingridients, |_| true)
+}
+
 bitflags! {
     #[derive(Debug, PartialEq, Clone, Copy)]
     pub struct Property: u8 {
@@ -114,7 +173,7 @@ impl FromStr for Ingredient {
             .find(':')
             .ok_or(FromStrIntoIngredientError::NameNotProvided)?;
         let name = s[..colon].trim().to_owned();
-        if name.len() == 0 {
+        if name.is_empty() {
             return Err(FromStrIntoIngredientError::NameNotProvided);
         }
 
@@ -341,3 +400,10 @@ mod tests {
         }
     }
 }
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+
+    helpers::example_tests!(Day15; butterscotch_and_cinnamon);
+}
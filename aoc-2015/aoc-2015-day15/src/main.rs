@@ -1,17 +1,9 @@
-use std::io::BufRead;
-
-use aoc_2015_day15::{Cookie, Ingredient};
-use itertools::Itertools;
+use aoc_2015_day15::{find_best_score, find_best_score_if, parse, Day15};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let filepath = helpers::get_filepath_from_args();
-    let f = std::fs::File::open(filepath)?;
-    let reader = std::io::BufReader::new(f);
+    let input = helpers::input::from_args::<Day15>()?;
 
-    let ingridients = reader
-        .lines()
-        .flat_map(|l| l.map(|l| l.parse::<Ingredient>()))
-        .collect::<Result<Vec<Ingredient>, _>>()?;
+    let ingridients = parse(&input)?;
     let ingridients = ingridients.iter().collect::<Vec<_>>();
 
     let best_score1 = find_best_score(ingridients.as_slice());
@@ -25,30 +17,3 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
-
-fn find_best_score_if(
-    ingridients: &[&Ingredient],
-    predicate: impl Fn(&Cookie) -> bool,
-) -> Option<u32> {
-    (0..ingridients.len())
-        .combinations_with_replacement(100)
-        .filter_map(|c| {
-            let ingridients = c
-                .into_iter()
-                .counts()
-                .into_iter()
-                .map(|c| (ingridients[c.0], c.1 as u32))
-                .collect::<Vec<_>>();
-            let cookie = Cookie::new(ingridients);
-            if predicate(&cookie) {
-                Some(cookie.score_without_calories())
-            } else {
-                None
-            }
-        })
-        .max()
-}
-
-fn find_best_score(ingridients: &[&Ingredient]) -> Option<u32> {
-    find_best_score_if(ingridients, |_| true)
-}
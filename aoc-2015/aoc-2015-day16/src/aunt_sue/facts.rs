@@ -13,6 +13,12 @@ pub struct FactsMatcher {
     ord: HashMap<String, Ordering>,
 }
 
+impl Default for FactsMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl FactsMatcher {
     pub fn new() -> Self {
         Self {
@@ -43,10 +49,7 @@ impl FactsMatcher {
     }
 
     fn wanted_ord(&self, key: &str) -> Ordering {
-        self.ord
-            .get(key)
-            .map(|&o| Ordering::from(o))
-            .unwrap_or(Ordering::Equal)
+        self.ord.get(key).copied().unwrap_or(Ordering::Equal)
     }
 }
 
@@ -65,9 +68,9 @@ impl std::str::FromStr for Facts {
     ///
     /// goldfish: 10, trees: 8, perfumes: 6
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut iter = s.split(", ");
+        let iter = s.split(", ");
         let mut facts = HashMap::new();
-        while let Some(fact_with_amount) = iter.next() {
+        for fact_with_amount in iter {
             let (fact, amount) =
                 fact_with_amount
                     .split_once(": ")
@@ -107,8 +110,8 @@ mod tests {
                     let valid = Facts(HashMap::from([("a".to_owned(), 5)]));
                     let invalid = Facts(HashMap::from([("a".to_owned(), 15)]));
 
-                    assert_eq!(matcher.is_possible_match(&facts, &valid), true);
-                    assert_eq!(matcher.is_possible_match(&facts, &invalid), false);
+                    assert!(matcher.is_possible_match(&facts, &valid));
+                    assert!(!matcher.is_possible_match(&facts, &invalid));
                 }
 
                 #[test]
@@ -123,8 +126,8 @@ mod tests {
                     let other = Facts(HashMap::from([("a".to_owned(), 15)]));
                     let invalid = Facts(HashMap::from([("a".to_owned(), 5)]));
 
-                    assert_eq!(matcher.is_possible_match(&facts, &other), true);
-                    assert_eq!(matcher.is_possible_match(&facts, &invalid), false);
+                    assert!(matcher.is_possible_match(&facts, &other));
+                    assert!(!matcher.is_possible_match(&facts, &invalid));
                 }
 
                 #[test]
@@ -141,8 +144,8 @@ mod tests {
                     let valid = Facts(HashMap::from([("a".to_owned(), 12), ("b".to_owned(), 18)]));
                     let invalid = Facts(HashMap::from([("a".to_owned(), 8), ("b".to_owned(), 20)]));
 
-                    assert_eq!(matcher.is_possible_match(&facts, &valid), true);
-                    assert_eq!(matcher.is_possible_match(&facts, &invalid), false);
+                    assert!(matcher.is_possible_match(&facts, &valid));
+                    assert!(!matcher.is_possible_match(&facts, &invalid));
                 }
             }
 
@@ -158,7 +161,7 @@ mod tests {
                     ]));
                     let other = Facts(HashMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)]));
                     let matcher = FactsMatcher::new();
-                    assert_eq!(matcher.is_possible_match(&facts, &other), true);
+                    assert!(matcher.is_possible_match(&facts, &other));
                 }
 
                 #[test]
@@ -170,7 +173,7 @@ mod tests {
                     ]));
                     let other = Facts(HashMap::from([("a".to_owned(), 1), ("b".to_owned(), 3)]));
                     let matcher = FactsMatcher::new();
-                    assert_eq!(matcher.is_possible_match(&facts, &other), false);
+                    assert!(!matcher.is_possible_match(&facts, &other));
                 }
 
                 #[test]
@@ -182,7 +185,7 @@ mod tests {
                     ]));
                     let other = Facts(HashMap::from([("a".to_owned(), 1), ("d".to_owned(), 2)]));
                     let matcher = FactsMatcher::new();
-                    assert_eq!(matcher.is_possible_match(&facts, &other), false);
+                    assert!(!matcher.is_possible_match(&facts, &other));
                 }
             }
         }
@@ -1 +1,84 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use aunt_sue::{
+    facts::{Facts, FactsMatcher},
+    AuntSue,
+};
+use helpers::{parse_lines, AocError, Solution};
+
 pub mod aunt_sue;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 16;
+
+    type Input = Vec<AuntSue>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(aunts: &Self::Input) -> Result<Self::Answer1, AocError> {
+        let possible_aunts =
+            find_possible_aunts(aunts.iter(), &known_facts(), &FactsMatcher::new());
+        the_only_aunt(&possible_aunts)
+    }
+
+    fn part2(aunts: &Self::Input) -> Result<Self::Answer2, AocError> {
+        let possible_aunts = find_possible_aunts(aunts.iter(), &known_facts(), &retroencabulator());
+        the_only_aunt(&possible_aunts)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<AuntSue>, AocError> {
+    parse_lines(input, str::parse)
+}
+
+/// Facts, detected by MFCSAM from the gift.
+pub fn known_facts() -> Facts {
+    Facts(HashMap::from([
+        ("children".to_owned(), 3),
+        ("cats".to_owned(), 7),
+        ("samoyeds".to_owned(), 2),
+        ("pomeranians".to_owned(), 3),
+        ("akitas".to_owned(), 0),
+        ("vizslas".to_owned(), 0),
+        ("goldfish".to_owned(), 5),
+        ("trees".to_owned(), 3),
+        ("cars".to_owned(), 2),
+        ("perfumes".to_owned(), 1),
+    ]))
+}
+
+/// Matcher with outdated retroencabulator ranges for part 2.
+pub fn retroencabulator() -> FactsMatcher {
+    FactsMatcher::new()
+        .with_ord("cats", Ordering::Greater)
+        .with_ord("trees", Ordering::Greater)
+        .with_ord("pomeranians", Ordering::Less)
+        .with_ord("goldfish", Ordering::Less)
+}
+
+pub fn find_possible_aunts<'a>(
+    aunts: impl Iterator<Item = &'a AuntSue>,
+    known_facts: &Facts,
+    matcher: &FactsMatcher,
+) -> Vec<u32> {
+    aunts
+        .filter(|&aunt| matcher.is_possible_match(known_facts, &aunt.facts))
+        .map(|aunt| aunt.no)
+        .collect()
+}
+
+fn the_only_aunt(possible_aunts: &[u32]) -> Result<u32, AocError> {
+    match possible_aunts {
+        [aunt] => Ok(*aunt),
+        _ => Err(AocError::compute(format!(
+            "expected exactly one aunt, found {possible_aunts:?}"
+        ))),
+    }
+}
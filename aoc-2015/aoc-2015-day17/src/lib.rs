@@ -0,0 +1,66 @@
+use helpers::{parse_lines, AocError, Solution};
+use itertools::Itertools;
+
+pub const EGGNOG_LITERS: u32 = 150;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 17;
+
+    type Input = Vec<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(containers: &Self::Input) -> Result<Self::Answer1, AocError> {
+        Ok(combinations_count(containers, EGGNOG_LITERS))
+    }
+
+    fn part2(containers: &Self::Input) -> Result<Self::Answer2, AocError> {
+        Ok(different_min_ways_count(containers, EGGNOG_LITERS))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<u32>, AocError> {
+    parse_lines(input, str::parse)
+}
+
+pub fn combinations_count(containers: &[u32], liters: u32) -> usize {
+    containers
+        .iter()
+        .powerset()
+        .filter(|c| c.iter().copied().sum::<u32>() == liters)
+        .count()
+}
+
+pub fn different_min_ways_count(containers: &[u32], liters: u32) -> usize {
+    containers
+        .iter()
+        .powerset()
+        .filter(|c| c.iter().copied().sum::<u32>() == liters)
+        .min_set_by(|a, b| a.len().cmp(&b.len()))
+        .len()
+}
+
+#[cfg(test)]
+mod examples {
+    use helpers::Part;
+
+    use super::*;
+
+    /// Puzzle example stores 25 liters.
+    fn store_25_liters(input: &str, part: Part) -> usize {
+        let containers = parse(input).unwrap();
+        match part {
+            Part::One => combinations_count(&containers, 25),
+            Part::Two => different_min_ways_count(&containers, 25),
+        }
+    }
+
+    helpers::example_tests!(2015, 17, store_25_liters; containers);
+}
@@ -1,40 +1,14 @@
-use std::io::BufRead;
-
-use itertools::Itertools;
+use aoc_2015_day17::{combinations_count, different_min_ways_count, parse, Day17, EGGNOG_LITERS};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let filepath = helpers::get_filepath_from_args();
-    let file = std::fs::File::open(filepath)?;
-    let containers = get_numbers(file)?;
+    let input = helpers::input::from_args::<Day17>()?;
+    let containers = parse(&input)?;
 
-    println!("Part 1: {}", combinations_count(&containers));
-    println!("Part 2: {}", different_min_ways_count(&containers));
+    println!("Part 1: {}", combinations_count(&containers, EGGNOG_LITERS));
+    println!(
+        "Part 2: {}",
+        different_min_ways_count(&containers, EGGNOG_LITERS)
+    );
 
     Ok(())
 }
-
-fn combinations_count(containers: &[u32]) -> usize {
-    containers
-        .iter()
-        .powerset()
-        .filter(|c| c.into_iter().copied().sum::<u32>() == 150)
-        .count()
-}
-
-fn different_min_ways_count(containers: &[u32]) -> usize {
-    containers
-        .iter()
-        .powerset()
-        .filter(|c| c.into_iter().copied().sum::<u32>() == 150)
-        .min_set_by(|a, b| a.len().cmp(&b.len()))
-        .len()
-}
-
-fn get_numbers(input: impl std::io::Read) -> Result<Vec<u32>, Box<dyn std::error::Error>> {
-    let reader = std::io::BufReader::new(input);
-    let ret = reader
-        .lines()
-        .flat_map(|line| line.map(|line| line.parse::<u32>()))
-        .collect::<Result<Vec<u32>, _>>()?;
-    Ok(ret)
-}
@@ -1 +1,55 @@
+use std::str::FromStr;
+
+use helpers::{AocError, Solution};
+use light::Grid;
+
 pub mod light;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 18;
+
+    type Input = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Grid::from_str(input).map_err(AocError::parse)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Self::Answer1, AocError> {
+        Ok(lights_on_after_steps(grid.clone(), 100))
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Self::Answer2, AocError> {
+        let grid = grid.clone().with_stucked_corners();
+        Ok(lights_on_after_steps(grid, 100))
+    }
+}
+
+pub fn lights_on_after_steps(mut grid: Grid, steps: usize) -> usize {
+    for _ in 0..steps {
+        grid = grid.next_step();
+    }
+    grid.count_on()
+}
+
+#[cfg(test)]
+mod examples {
+    use helpers::Part;
+
+    use super::*;
+
+    /// Puzzle examples make 4 steps, and 5 steps with stucked corners.
+    fn few_steps(input: &str, part: Part) -> usize {
+        let grid = Grid::from_str(input).unwrap();
+        match part {
+            Part::One => lights_on_after_steps(grid, 4),
+            Part::Two => lights_on_after_steps(grid.with_stucked_corners(), 5),
+        }
+    }
+
+    helpers::example_tests!(2015, 18, few_steps; grid);
+}
@@ -25,7 +25,7 @@ impl Neighbours {
             self.n, self.ne, self.e, self.se, self.s, self.sw, self.w, self.nw,
         ]
         .into_iter()
-        .filter_map(|x| x)
+        .flatten()
         .filter(|&x| x == Light::On)
         .count()
     }
@@ -209,7 +209,7 @@ impl FromStr for Grid {
             })
             .collect::<Result<Vec<Vec<Light>>, Self::Err>>()?;
 
-        if lights.len() == 0 {
+        if lights.is_empty() {
             return Err(ParseError::Empty);
         }
 
@@ -261,7 +261,7 @@ mod tests {
         mod next_step {
             use super::*;
 
-            const STEPS: &[&'static str] = &[
+            const STEPS: &[&str] = &[
                 r#"
                 .#.#.#
                 ...##.
@@ -304,7 +304,7 @@ mod tests {
 "#,
             ];
 
-            const STUCKED_STEPS: &[&'static str] = &[
+            const STUCKED_STEPS: &[&str] = &[
                 r#"
                 ##.#.#
                 ...##.
@@ -382,7 +382,7 @@ mod tests {
         mod get_neighbours {
             use super::*;
 
-            const INPUT: &'static str = "#.#.\n#..#\n###.";
+            const INPUT: &str = "#.#.\n#..#\n###.";
 
             #[test]
             fn middle() {
@@ -448,7 +448,7 @@ mod tests {
         mod from_str {
             use super::*;
 
-            const INPUT: &'static str = "#.#.\n#..#\n###.";
+            const INPUT: &str = "#.#.\n#..#\n###.";
 
             #[test]
             fn works() {
@@ -1,26 +1,18 @@
 use std::str::FromStr;
 
-use aoc_2015_day18::light::Grid;
+use aoc_2015_day18::{light::Grid, lights_on_after_steps, Day18};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let filepath = helpers::get_filepath_from_args();
-    let contents = std::fs::read_to_string(filepath)?;
+    let contents = helpers::input::from_args::<Day18>()?;
 
     let grid = Grid::from_str(&contents)?;
 
-    let part1_res = lights_on_after_100_steps(grid.clone());
+    let part1_res = lights_on_after_steps(grid.clone(), 100);
     println!("Part1: {} lights are on after 100 steps", part1_res);
 
     let grid = grid.with_stucked_corners();
-    let part2_res = lights_on_after_100_steps(grid);
+    let part2_res = lights_on_after_steps(grid, 100);
     println!("Part2: {} lights are on after 100 steps", part2_res);
 
     Ok(())
 }
-
-fn lights_on_after_100_steps(mut grid: Grid) -> usize {
-    for _ in 0..100 {
-        grid = grid.next_step();
-    }
-    grid.count_on()
-}
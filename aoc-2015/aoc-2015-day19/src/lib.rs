@@ -1 +1,42 @@
+use helpers::{AocError, Solution};
+use replacements::{min_downgrade_steps, ParseError, Replacements};
+
 pub mod replacements;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 19;
+
+    type Input = (Replacements, String);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let (replacements, molecula) = parse(input).map_err(AocError::parse)?;
+        Ok((replacements, molecula.to_owned()))
+    }
+
+    fn part1((replacements, molecula): &Self::Input) -> Result<Self::Answer1, AocError> {
+        Ok(replacements.upgraded_moleculas(molecula).count())
+    }
+
+    fn part2((replacements, molecula): &Self::Input) -> Result<Self::Answer2, AocError> {
+        Ok(min_downgrade_steps(molecula, replacements))
+    }
+}
+
+/// Splits input into replacements and the medicine molecula, which goes last.
+pub fn parse(input: &str) -> Result<(Replacements, &str), ParseError> {
+    let input = input.trim();
+    let (replacements, molecula) = input.split_at(input.rfind('\n').ok_or(ParseError::NoMolecula)?);
+    Ok((replacements.parse()?, molecula.trim()))
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+
+    helpers::example_tests!(Day19; hoh, hohoho);
+}
@@ -57,7 +57,7 @@ struct DowngradedMoleculas<'a> {
 }
 
 impl<'a> DowngradedMoleculas<'a> {
-    pub fn new(replacements: &'a Vec<(String, String)>, molecula: &'a str) -> Self {
+    pub fn new(replacements: &'a [(String, String)], molecula: &'a str) -> Self {
         DowngradedMoleculas {
             replacements: replacements.iter(),
             current_replacement: None,
@@ -71,7 +71,7 @@ impl<'a> Iterator for DowngradedMoleculas<'a> {
     type Item = String;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(&(ref to, ref from)) = self.current_replacement {
+        if let Some((to, from)) = self.current_replacement {
             let idx = self.molecula[self.molecula_idx..]
                 .find(from)
                 .map(|idx| idx + self.molecula_idx);
@@ -80,24 +80,24 @@ impl<'a> Iterator for DowngradedMoleculas<'a> {
 
                 let mut ret = String::new();
                 ret.push_str(&self.molecula[..idx]);
-                ret.push_str(&to);
+                ret.push_str(to);
                 ret.push_str(&self.molecula[self.molecula_idx..]);
                 if ret != "e" && ret.chars().any(|c| c == 'e') {
-                    return self.next();
+                    self.next()
                 } else {
-                    return Some(ret);
+                    Some(ret)
                 }
             } else {
                 self.molecula_idx = 0;
                 self.current_replacement = self.replacements.next();
-                return self.next();
+                self.next()
             }
         } else {
             self.current_replacement = self.replacements.next();
             if self.current_replacement.is_some() {
-                return self.next();
+                self.next()
             } else {
-                return None;
+                None
             }
         }
     }
@@ -111,7 +111,7 @@ struct UpgradedMoleculas<'a> {
 }
 
 impl<'a> UpgradedMoleculas<'a> {
-    pub fn new(replacements: &'a Vec<(String, String)>, molecula: &'a str) -> Self {
+    pub fn new(replacements: &'a [(String, String)], molecula: &'a str) -> Self {
         UpgradedMoleculas {
             replacements: replacements.iter(),
             current_replacement: None,
@@ -125,7 +125,7 @@ impl<'a> Iterator for UpgradedMoleculas<'a> {
     type Item = String;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(&(ref from, ref to)) = self.current_replacement {
+        if let Some((from, to)) = self.current_replacement {
             let idx = self.molecula[self.molecula_idx..]
                 .find(from)
                 .map(|idx| idx + self.molecula_idx);
@@ -134,20 +134,20 @@ impl<'a> Iterator for UpgradedMoleculas<'a> {
 
                 let mut ret = String::new();
                 ret.push_str(&self.molecula[..idx]);
-                ret.push_str(&to);
+                ret.push_str(to);
                 ret.push_str(&self.molecula[self.molecula_idx..]);
-                return Some(ret);
+                Some(ret)
             } else {
                 self.molecula_idx = 0;
                 self.current_replacement = self.replacements.next();
-                return self.next();
+                self.next()
             }
         } else {
             self.current_replacement = self.replacements.next();
             if self.current_replacement.is_some() {
-                return self.next();
+                self.next()
             } else {
-                return None;
+                None
             }
         }
     }
@@ -157,6 +157,8 @@ impl<'a> Iterator for UpgradedMoleculas<'a> {
 pub enum ParseError {
     #[error("No delimeter")]
     NoDelimeter,
+    #[error("No newline before molecula")]
+    NoMolecula,
 }
 
 impl FromStr for Replacements {
@@ -165,7 +167,7 @@ impl FromStr for Replacements {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let ret = s
             .lines()
-            .filter(|l| l.trim().len() != 0)
+            .filter(|l| !l.trim().is_empty())
             .map(|l| -> Result<(String, String), Self::Err> {
                 let (from, to) = l.split_once("=>").ok_or(ParseError::NoDelimeter)?;
                 Ok((from.trim().to_owned(), to.trim().to_owned()))
@@ -0,0 +1,98 @@
+use helpers::{parse_lines, AocError, Solution};
+use present_box::PresentBox;
+use strategy::{Ribbon, WrappingPaper, WrappingStrategy};
+
+pub mod present_box;
+pub mod report;
+pub mod strategy;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 2;
+
+    type Input = Vec<PresentBox>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, AocError> {
+        wrapping_paper_needed(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, AocError> {
+        ribbon_needed(input)
+    }
+}
+
+/// Parses a box per line. Errors tell the number of the bad line.
+pub fn parse(input: &str) -> Result<Vec<PresentBox>, AocError> {
+    parse_lines(input, str::parse::<PresentBox>)
+}
+
+pub fn wrapping_paper_needed(present_boxes: &[PresentBox]) -> Result<u64, AocError> {
+    total_needed(&WrappingPaper, present_boxes)
+}
+
+pub fn ribbon_needed(present_boxes: &[PresentBox]) -> Result<u64, AocError> {
+    total_needed(&Ribbon, present_boxes)
+}
+
+/// Like [`WrappingStrategy::total`], but an overflow tells the line of the box it happened at.
+pub fn total_needed(
+    strategy: &(impl WrappingStrategy + ?Sized),
+    present_boxes: &[PresentBox],
+) -> Result<u64, AocError> {
+    present_boxes
+        .iter()
+        .enumerate()
+        .try_fold(0u64, |total, (i, present_box)| {
+            strategy
+                .needed(present_box)
+                .and_then(|needed| total.checked_add(needed))
+                .ok_or_else(|| {
+                    AocError::compute(format!("{} doesn't fit in u64", strategy.name()))
+                        .at_line(i + 1)
+                })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_error_has_line() {
+        let err = parse("2x3x4\n1x1x10\n1x1\n").unwrap_err();
+        assert_eq!(err.line, Some(3));
+    }
+
+    #[test]
+    fn too_large_box_is_a_parse_error() {
+        let err = parse("2x3x4\n4294967295x4294967295x1\n").unwrap_err();
+        assert_eq!(err.line, Some(2));
+    }
+
+    #[test]
+    fn overflow_has_line() {
+        let boxes = parse("2x3x4\n16843009x54485x20101251\n").unwrap();
+        assert!(wrapping_paper_needed(&boxes).is_ok());
+        let err = ribbon_needed(&boxes).unwrap_err();
+        assert_eq!(err.line, Some(2));
+        assert_eq!(
+            err.to_string(),
+            "[line 2] Compute error: ribbon doesn't fit in u64"
+        );
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+
+    helpers::example_tests!(Day2; one_box, long_box);
+}
@@ -0,0 +1,144 @@
+use std::{num::ParseIntError, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PresentBoxError {
+    #[error("expected 3 dimensions like `2x3x4`, got {0}")]
+    DimensionCount(usize),
+    #[error("invalid dimension '{0}': {1}")]
+    InvalidDimension(String, #[source] ParseIntError),
+    #[error("box {0}x{1}x{2} is too large")]
+    TooLarge(u32, u32, u32),
+}
+
+/// Box with `length`x`width`x`height` dimensions.
+///
+/// Areas and lengths derived from the dimensions are `u64`,
+/// so a box is only accepted if its volume and surface area fit in `u64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PresentBox {
+    length: u32,
+    width: u32,
+    height: u32,
+}
+
+impl PresentBox {
+    pub fn new(length: u32, width: u32, height: u32) -> Result<Self, PresentBoxError> {
+        let [l, w, h] = [length, width, height].map(u64::from);
+        let volume = (l * w).checked_mul(h);
+        // Each side area fits, as dimensions are `u32`.
+        let surface_area = (l * w)
+            .checked_add(w * h)
+            .and_then(|area| area.checked_add(h * l))
+            .and_then(|area| area.checked_mul(2));
+        volume
+            .and(surface_area)
+            .ok_or(PresentBoxError::TooLarge(length, width, height))?;
+        Ok(Self {
+            length,
+            width,
+            height,
+        })
+    }
+
+    pub fn dimensions(&self) -> [u32; 3] {
+        [self.length, self.width, self.height]
+    }
+
+    pub fn volume(&self) -> u64 {
+        self.length as u64 * self.width as u64 * self.height as u64
+    }
+
+    pub fn surface_area(&self) -> u64 {
+        let [l, w, h] = self.dimensions().map(u64::from);
+        2 * (l * w + w * h + h * l)
+    }
+
+    /// Two shortest sides.
+    pub fn smallest_side(&self) -> (u64, u64) {
+        let mut sides = self.dimensions();
+        sides.sort();
+        (sides[0] as u64, sides[1] as u64)
+    }
+
+    pub fn smallest_side_area(&self) -> u64 {
+        let (a, b) = self.smallest_side();
+        a * b
+    }
+}
+
+impl FromStr for PresentBox {
+    type Err = PresentBoxError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let dimensions = s
+            .trim()
+            .split('x')
+            .map(|dimension| {
+                dimension
+                    .parse()
+                    .map_err(|e| PresentBoxError::InvalidDimension(dimension.to_owned(), e))
+            })
+            .collect::<Result<Vec<u32>, _>>()?;
+        match dimensions[..] {
+            [length, width, height] => Self::new(length, width, height),
+            _ => Err(PresentBoxError::DimensionCount(dimensions.len())),
+        }
+    }
+}
+
+impl TryFrom<&str> for PresentBox {
+    type Error = PresentBoxError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let present_box: PresentBox = "2x3x4".parse().unwrap();
+        assert_eq!(present_box.dimensions(), [2, 3, 4]);
+        assert_eq!(present_box.surface_area(), 52);
+        assert_eq!(present_box.smallest_side(), (2, 3));
+        assert_eq!(present_box.volume(), 24);
+    }
+
+    #[test]
+    fn large_dimensions() {
+        let present_box: PresentBox = "1000x300x256".parse().unwrap();
+        assert_eq!(present_box.volume(), 76_800_000);
+        assert_eq!(present_box.smallest_side_area(), 76_800);
+
+        assert_eq!(
+            "4294967295x4294967295x2".parse::<PresentBox>(),
+            Err(PresentBoxError::TooLarge(u32::MAX, u32::MAX, 2))
+        );
+        // The volume fits, but the surface area doesn't.
+        assert_eq!(
+            "4294967295x4294967295x1".parse::<PresentBox>(),
+            Err(PresentBoxError::TooLarge(u32::MAX, u32::MAX, 1))
+        );
+        let flat: PresentBox = "4294967295x1x1".parse().unwrap();
+        assert_eq!(flat.surface_area(), 4 * u32::MAX as u64 + 2);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            "2x3".parse::<PresentBox>(),
+            Err(PresentBoxError::DimensionCount(2))
+        );
+        assert_eq!(
+            "2x3x4x5".parse::<PresentBox>(),
+            Err(PresentBoxError::DimensionCount(4))
+        );
+        assert!(matches!(
+            "2x-3x4".parse::<PresentBox>(),
+            Err(PresentBoxError::InvalidDimension(dimension, _)) if dimension == "-3"
+        ));
+    }
+}
@@ -0,0 +1,141 @@
+use std::io::{self, Write};
+
+use helpers::AocError;
+
+use crate::{
+    present_box::PresentBox,
+    strategy::{Ribbon, WrappingPaper, WrappingStrategy},
+    total_needed,
+};
+
+/// Costs of wrapping a single box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoxCost {
+    pub present_box: PresentBox,
+    pub paper: u64,
+    pub ribbon: u64,
+}
+
+/// Per-box costs of a packing order, in the order of the boxes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PackingReport {
+    pub boxes: Vec<BoxCost>,
+    pub total_paper: u64,
+    pub total_ribbon: u64,
+}
+
+impl PackingReport {
+    /// Report by the puzzle rules.
+    pub fn new(present_boxes: &[PresentBox]) -> Result<Self, AocError> {
+        Self::with_strategies(present_boxes, &WrappingPaper, &Ribbon)
+    }
+
+    /// Errors tell the line of the box, which amount doesn't fit in `u64`.
+    pub fn with_strategies(
+        present_boxes: &[PresentBox],
+        paper: &impl WrappingStrategy,
+        ribbon: &impl WrappingStrategy,
+    ) -> Result<Self, AocError> {
+        let total_paper = total_needed(paper, present_boxes)?;
+        let total_ribbon = total_needed(ribbon, present_boxes)?;
+        let boxes = present_boxes
+            .iter()
+            .map(|&present_box| BoxCost {
+                present_box,
+                // Totals fit, so every part of them does.
+                paper: paper.needed(&present_box).unwrap_or_default(),
+                ribbon: ribbon.needed(&present_box).unwrap_or_default(),
+            })
+            .collect();
+        Ok(Self {
+            boxes,
+            total_paper,
+            total_ribbon,
+        })
+    }
+
+    /// Box with the largest volume, the first one of equals.
+    pub fn largest(&self) -> Option<&BoxCost> {
+        self.boxes
+            .iter()
+            .rev()
+            .max_by_key(|b| b.present_box.volume())
+    }
+
+    /// Box with the smallest volume, the first one of equals.
+    pub fn smallest(&self) -> Option<&BoxCost> {
+        self.boxes.iter().min_by_key(|b| b.present_box.volume())
+    }
+
+    /// Writes one CSV row per box, numbered from 1, after a header row.
+    pub fn write_csv(&self, mut w: impl Write) -> io::Result<()> {
+        writeln!(w, "box,length,width,height,paper,ribbon")?;
+        for (i, cost) in self.boxes.iter().enumerate() {
+            let [length, width, height] = cost.present_box.dimensions();
+            writeln!(
+                w,
+                "{},{length},{width},{height},{},{}",
+                i + 1,
+                cost.paper,
+                cost.ribbon
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(boxes: &[&str]) -> PackingReport {
+        let boxes = boxes
+            .iter()
+            .map(|b| b.parse().unwrap())
+            .collect::<Vec<PresentBox>>();
+        PackingReport::new(&boxes).unwrap()
+    }
+
+    #[test]
+    fn totals() {
+        let report = report(&["2x3x4", "1x1x10"]);
+        assert_eq!(report.boxes[1].paper, 43);
+        assert_eq!((report.total_paper, report.total_ribbon), (101, 48));
+    }
+
+    #[test]
+    fn other_strategies() {
+        let boxes = ["2x3x4".parse().unwrap()];
+        let report = PackingReport::with_strategies(
+            &boxes,
+            &crate::strategy::PercentSlackPaper(50),
+            &crate::strategy::BowFreeRibbon,
+        )
+        .unwrap();
+        assert_eq!((report.total_paper, report.total_ribbon), (78, 10));
+    }
+
+    #[test]
+    fn largest_and_smallest() {
+        let report = report(&["2x3x4", "1x1x10", "4x3x2", "1x1x1"]);
+        assert_eq!(
+            report.largest().unwrap().present_box.dimensions(),
+            [2, 3, 4]
+        );
+        assert_eq!(
+            report.smallest().unwrap().present_box.dimensions(),
+            [1, 1, 1]
+        );
+        assert_eq!(PackingReport::new(&[]).unwrap().largest(), None);
+    }
+
+    #[test]
+    fn csv() {
+        let mut csv = Vec::new();
+        report(&["2x3x4", "1x1x10"]).write_csv(&mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "box,length,width,height,paper,ribbon\n1,2,3,4,58,34\n2,1,1,10,43,14\n"
+        );
+    }
+}
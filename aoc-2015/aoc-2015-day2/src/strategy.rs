@@ -0,0 +1,126 @@
+use crate::present_box::PresentBox;
+
+/// Rule of how much of a material a box needs to be wrapped.
+pub trait WrappingStrategy {
+    /// Name to tell strategies apart, e.g. in comparisons.
+    fn name(&self) -> String;
+
+    /// `None`, if the amount doesn't fit in `u64`.
+    fn needed(&self, present_box: &PresentBox) -> Option<u64>;
+
+    /// `None`, if the amount of any box or the total doesn't fit in `u64`.
+    fn total(&self, present_boxes: &[PresentBox]) -> Option<u64> {
+        present_boxes
+            .iter()
+            .try_fold(0u64, |total, b| total.checked_add(self.needed(b)?))
+    }
+}
+
+/// Paper by the puzzle: the surface area plus slack of the smallest side area.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WrappingPaper;
+
+impl WrappingStrategy for WrappingPaper {
+    fn name(&self) -> String {
+        "wrapping paper".to_owned()
+    }
+
+    fn needed(&self, present_box: &PresentBox) -> Option<u64> {
+        present_box
+            .surface_area()
+            .checked_add(present_box.smallest_side_area())
+    }
+}
+
+/// Paper for the surface area plus slack of a fixed percentage of it, rounded up.
+#[derive(Debug, Clone, Copy)]
+pub struct PercentSlackPaper(pub u64);
+
+impl WrappingStrategy for PercentSlackPaper {
+    fn name(&self) -> String {
+        format!("paper with {}% slack", self.0)
+    }
+
+    fn needed(&self, present_box: &PresentBox) -> Option<u64> {
+        let area = present_box.surface_area();
+        area.checked_add(area.checked_mul(self.0)?.div_ceil(100))
+    }
+}
+
+/// Ribbon by the puzzle: the smallest perimeter plus a bow as long as the volume.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Ribbon;
+
+impl WrappingStrategy for Ribbon {
+    fn name(&self) -> String {
+        "ribbon".to_owned()
+    }
+
+    fn needed(&self, present_box: &PresentBox) -> Option<u64> {
+        BowFreeRibbon
+            .needed(present_box)?
+            .checked_add(present_box.volume())
+    }
+}
+
+/// Ribbon for the smallest perimeter only.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BowFreeRibbon;
+
+impl WrappingStrategy for BowFreeRibbon {
+    fn name(&self) -> String {
+        "bow-free ribbon".to_owned()
+    }
+
+    fn needed(&self, present_box: &PresentBox) -> Option<u64> {
+        // Sides are `u32`, so this always fits.
+        let (a, b) = present_box.smallest_side();
+        Some(2 * (a + b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn boxes() -> Vec<PresentBox> {
+        vec!["2x3x4".parse().unwrap(), "1x1x10".parse().unwrap()]
+    }
+
+    #[test]
+    fn puzzle_rules() {
+        assert_eq!(WrappingPaper.total(&boxes()), Some(58 + 43));
+        assert_eq!(Ribbon.total(&boxes()), Some(34 + 14));
+    }
+
+    #[test]
+    fn percent_slack_paper() {
+        assert_eq!(PercentSlackPaper(0).total(&boxes()), Some(52 + 42));
+        // 52 + 5.2 and 42 + 4.2, rounded up.
+        assert_eq!(PercentSlackPaper(10).total(&boxes()), Some(58 + 47));
+    }
+
+    #[test]
+    fn bow_free_ribbon() {
+        assert_eq!(BowFreeRibbon.total(&boxes()), Some(10 + 4));
+    }
+
+    #[test]
+    fn overflow() {
+        // Volume is u64::MAX.
+        let deep: PresentBox = "16843009x54485x20101251".parse().unwrap();
+        assert!(WrappingPaper.needed(&deep).is_some());
+        assert_eq!(Ribbon.needed(&deep), None);
+        let flat: PresentBox = "1000000000x1000000000x1".parse().unwrap();
+        assert!(WrappingPaper.needed(&flat).is_some());
+        assert_eq!(PercentSlackPaper(10).needed(&flat), None);
+        assert_eq!(WrappingPaper.total(&[flat; 10]), None);
+    }
+
+    #[test]
+    fn strategies_compare_as_objects() {
+        let strategies: [&dyn WrappingStrategy; 2] = [&WrappingPaper, &PercentSlackPaper(5)];
+        let names = strategies.map(|s| s.name());
+        assert_eq!(names, ["wrapping paper", "paper with 5% slack"]);
+    }
+}
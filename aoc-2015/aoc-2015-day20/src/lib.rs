@@ -1,5 +1,44 @@
 use std::collections::HashMap;
 
+use helpers::{AocError, Solution};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 20;
+
+    type Input = u64;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input.trim().parse().map_err(AocError::parse)
+    }
+
+    fn part1(&amount: &Self::Input) -> Result<Self::Answer1, AocError> {
+        let house = first_house_with::<Deliver1>(amount);
+        Ok(house
+            .ok_or_else(|| AocError::compute("house not found"))?
+            .house)
+    }
+
+    fn part2(&amount: &Self::Input) -> Result<Self::Answer2, AocError> {
+        let house = first_house_with::<Deliver2>(amount);
+        Ok(house
+            .ok_or_else(|| AocError::compute("house not found"))?
+            .house)
+    }
+}
+
+/// First house, which got at least `amount` of presents.
+pub fn first_house_with<D>(amount: u64) -> Option<HouseWithPresents>
+where
+    D: Delieve + Default,
+{
+    PresentsIter::<D>::new().find(|house| house.amount >= amount)
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct HouseWithPresents {
     pub house: u32,
@@ -11,6 +50,15 @@ pub struct PresentsIter<D> {
     deliver: D,
 }
 
+impl<D> Default for PresentsIter<D>
+where
+    D: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<D> PresentsIter<D>
 where
     D: Default,
@@ -74,7 +122,7 @@ impl Delieve for Deliver2 {
                 if *entry >= 50 {
                     return None;
                 }
-                if house % elf == 0 {
+                if house.is_multiple_of(elf) {
                     *entry += 1;
                     Some(elf as u64 * 11)
                 } else {
@@ -91,7 +139,7 @@ fn find_divisors(n: u32) -> Vec<u32> {
     let sqrt_n = (n as f64).sqrt() as u32;
 
     for i in 1..=sqrt_n {
-        if n % i == 0 {
+        if n.is_multiple_of(i) {
             divisors.push(i);
 
             if n / i != i {
@@ -136,11 +184,7 @@ mod tests {
             );
         }
 
-        let house51 = presents
-            .by_ref()
-            .skip_while(|house| house.house < 51)
-            .next()
-            .unwrap();
+        let house51 = presents.by_ref().find(|house| house.house >= 51).unwrap();
 
         assert_eq!(house51.house, 51);
         assert_eq!(house51.amount, (3 + 17 + 51) * 11);
@@ -157,3 +201,10 @@ mod tests {
         assert_eq!(find_divisors(52), [1, 2, 4, 13, 26, 52]);
     }
 }
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+
+    helpers::example_tests!(Day20; seventy);
+}
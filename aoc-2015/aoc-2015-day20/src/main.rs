@@ -1,24 +1,20 @@
-use aoc_2015_day20::{Deliver1, Deliver2, PresentsIter};
+use aoc_2015_day20::{first_house_with, Day20, Deliver1, Deliver2};
 
-fn main() {
-    part1();
-    part2();
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let amount = helpers::input::from_args::<Day20>()?.trim().parse()?;
+    part1(amount);
+    part2(amount);
+    Ok(())
 }
 
-fn part1() {
+fn part1(amount: u64) {
     println!("Running part 1...");
-    let house = PresentsIter::<Deliver1>::new()
-        .skip_while(|house| house.amount < 36000000)
-        .next()
-        .unwrap();
+    let house = first_house_with::<Deliver1>(amount);
     println!("Part 1: {house:?}");
 }
 
-fn part2() {
+fn part2(amount: u64) {
     println!("Running part 2...");
-    let house = PresentsIter::<Deliver2>::new()
-        .skip_while(|house| house.amount < 36000000)
-        .next()
-        .unwrap();
+    let house = first_house_with::<Deliver2>(amount);
     println!("Part 2: {house:?}");
 }
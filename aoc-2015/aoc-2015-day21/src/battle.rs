@@ -46,7 +46,7 @@ mod tests {
             damage: 7,
             armor: 2,
         };
-        assert_eq!(fight_till_death(&mut f1, &mut f2), true);
+        assert!(fight_till_death(&mut f1, &mut f2));
         assert_eq!(f1.hp(), 2);
         assert_eq!(f2.hp(), 0);
     }
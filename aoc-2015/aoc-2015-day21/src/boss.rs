@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use crate::battle::Fighter;
 
 #[derive(Debug, Clone)]
@@ -21,6 +23,62 @@ impl Fighter for Boss {
     }
 
     fn get_hit(&mut self, damage: u32) {
-        self.hp = self.hp.checked_sub(damage).unwrap_or(0);
+        self.hp = self.hp.saturating_sub(damage);
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+    #[error("Missing stat: {0}")]
+    MissingStat(&'static str),
+    #[error("Invalid line: {0}")]
+    InvalidLine(String),
+    #[error("Failed to parse int: {0}")]
+    ParseInt(#[from] std::num::ParseIntError),
+}
+
+impl FromStr for Boss {
+    type Err = ParseError;
+
+    /// Example:
+    ///
+    /// Hit Points: 103
+    /// Damage: 9
+    /// Armor: 2
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mut hp, mut damage, mut armor) = (None, None, None);
+        for line in s.lines().filter(|l| !l.trim().is_empty()) {
+            let (stat, value) = line
+                .split_once(':')
+                .ok_or_else(|| ParseError::InvalidLine(line.to_owned()))?;
+            let value = Some(value.trim().parse()?);
+            match stat.trim() {
+                "Hit Points" => hp = value,
+                "Damage" => damage = value,
+                "Armor" => armor = value,
+                _ => return Err(ParseError::InvalidLine(line.to_owned())),
+            }
+        }
+        Ok(Boss {
+            hp: hp.ok_or(ParseError::MissingStat("Hit Points"))?,
+            damage: damage.ok_or(ParseError::MissingStat("Damage"))?,
+            armor: armor.ok_or(ParseError::MissingStat("Armor"))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str_works() {
+        let boss = Boss::from_str("Hit Points: 103\nDamage: 9\nArmor: 2\n").unwrap();
+        assert_eq!((boss.hp, boss.damage, boss.armor), (103, 9, 2));
+    }
+
+    #[test]
+    fn from_str_missing_stat() {
+        assert!(Boss::from_str("Hit Points: 103\nDamage: 9").is_err());
     }
 }
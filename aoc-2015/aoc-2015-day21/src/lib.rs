@@ -1,5 +1,71 @@
+use boss::Boss;
+use helpers::{AocError, Solution};
+use player::Player;
+use shop::Shop;
+
 pub mod battle;
 pub mod boss;
 pub mod items;
 pub mod player;
 pub mod shop;
+
+pub const PLAYER_HP: u32 = 100;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 21;
+
+    type Input = Boss;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input.parse().map_err(AocError::parse)
+    }
+
+    fn part1(boss: &Self::Input) -> Result<Self::Answer1, AocError> {
+        find_min_cost_win(&Shop::aoc_item_shop(), PLAYER_HP, boss)
+            .ok_or_else(|| AocError::compute("no way to win"))
+    }
+
+    fn part2(boss: &Self::Input) -> Result<Self::Answer2, AocError> {
+        find_max_cost_lose(&Shop::aoc_item_shop(), PLAYER_HP, boss)
+            .ok_or_else(|| AocError::compute("no way to lose"))
+    }
+}
+
+pub fn find_max_cost_lose(shop: &Shop, player_hp: u32, boss: &Boss) -> Option<u32> {
+    let mut max_cost: Option<u32> = None;
+    for equip in shop.player_equip_combinations() {
+        let mut boss = boss.clone();
+        let mut player = Player {
+            hp: player_hp,
+            equip,
+        };
+        let player_win = battle::fight_till_death(&mut player, &mut boss);
+        if !player_win {
+            let equip_cost = player.equip.total_cost();
+            max_cost = max_cost.map_or(Some(equip_cost), |max_cost| Some(max_cost.max(equip_cost)));
+        }
+    }
+    max_cost
+}
+
+pub fn find_min_cost_win(shop: &Shop, player_hp: u32, boss: &Boss) -> Option<u32> {
+    let mut min_cost: Option<u32> = None;
+    for equip in shop.player_equip_combinations() {
+        let mut boss = boss.clone();
+        let mut player = Player {
+            hp: player_hp,
+            equip,
+        };
+        let player_win = battle::fight_till_death(&mut player, &mut boss);
+        if player_win {
+            let equip_cost = player.equip.total_cost();
+            min_cost = min_cost.map_or(Some(equip_cost), |min_cost| Some(min_cost.min(equip_cost)));
+        }
+    }
+    min_cost
+}
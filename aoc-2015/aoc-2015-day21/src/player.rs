@@ -65,6 +65,6 @@ impl Fighter for Player {
     }
 
     fn get_hit(&mut self, damage: u32) {
-        self.hp = self.hp.checked_sub(damage).unwrap_or(0);
+        self.hp = self.hp.saturating_sub(damage);
     }
 }
@@ -12,13 +12,111 @@ pub struct Shop {
 }
 
 impl Shop {
+    /// The item shop from the puzzle.
+    pub fn aoc_item_shop() -> Shop {
+        Shop {
+            weapons: vec![
+                Weapon {
+                    name: "Dagger".to_owned(),
+                    cost: 8,
+                    damage: 4,
+                },
+                Weapon {
+                    name: "Shortsword".to_owned(),
+                    cost: 10,
+                    damage: 5,
+                },
+                Weapon {
+                    name: "Warhammer".to_owned(),
+                    cost: 25,
+                    damage: 6,
+                },
+                Weapon {
+                    name: "Longsword".to_owned(),
+                    cost: 40,
+                    damage: 7,
+                },
+                Weapon {
+                    name: "Greataxe".to_owned(),
+                    cost: 74,
+                    damage: 8,
+                },
+            ],
+            armors: vec![
+                Armor {
+                    name: "Leather".to_owned(),
+                    cost: 13,
+                    armor: 1,
+                },
+                Armor {
+                    name: "Chainmail".to_owned(),
+                    cost: 31,
+                    armor: 2,
+                },
+                Armor {
+                    name: "Splintmail".to_owned(),
+                    cost: 53,
+                    armor: 3,
+                },
+                Armor {
+                    name: "Bandedmail".to_owned(),
+                    cost: 75,
+                    armor: 4,
+                },
+                Armor {
+                    name: "Platemail".to_owned(),
+                    cost: 102,
+                    armor: 5,
+                },
+            ],
+            rings: vec![
+                Ring {
+                    name: "Damage +1".to_owned(),
+                    cost: 25,
+                    damage: 1,
+                    armor: 0,
+                },
+                Ring {
+                    name: "Damage +2".to_owned(),
+                    cost: 50,
+                    damage: 2,
+                    armor: 0,
+                },
+                Ring {
+                    name: "Damage +3".to_owned(),
+                    cost: 100,
+                    damage: 3,
+                    armor: 0,
+                },
+                Ring {
+                    name: "Defence +1".to_owned(),
+                    cost: 20,
+                    damage: 0,
+                    armor: 1,
+                },
+                Ring {
+                    name: "Defence +2".to_owned(),
+                    cost: 40,
+                    damage: 0,
+                    armor: 2,
+                },
+                Ring {
+                    name: "Defence +3".to_owned(),
+                    cost: 80,
+                    damage: 0,
+                    armor: 3,
+                },
+            ],
+        }
+    }
+
     pub fn player_equip_combinations(&self) -> impl Iterator<Item = Equip> + '_ {
         let weapons = self.weapons.iter();
-        let armors = self.armors.iter().map(|a| Some(a)).chain(vec![None]);
+        let armors = self.armors.iter().map(Some).chain(vec![None]);
         let rings = self
             .rings
             .iter()
-            .map(|r| Some(r))
+            .map(Some)
             .chain(vec![None])
             .combinations(2);
         iproduct!(weapons, armors, rings).map(|(w, a, r)| Equip {
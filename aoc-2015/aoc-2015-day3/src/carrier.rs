@@ -0,0 +1,160 @@
+use crate::{
+    delivery::DeliveryMap,
+    moves::{Moves, Point},
+};
+
+pub trait Carrier {
+    fn visit(self, delivery_map: &mut DeliveryMap);
+}
+
+/// A single carrier, which visits every point in a path according to moves
+pub struct SingleCarrier<'a> {
+    start_point: Point,
+    moves: &'a Moves,
+}
+
+impl<'a> SingleCarrier<'a> {
+    pub fn new(start_point: Point, moves: &'a Moves) -> Self {
+        Self { start_point, moves }
+    }
+}
+
+impl<'a> Carrier for SingleCarrier<'a> {
+    fn visit(self, delivery_map: &mut DeliveryMap) {
+        let mut current = self.start_point;
+        delivery_map.visit_point(&current);
+        for mv in self.moves {
+            current += mv;
+            delivery_map.visit_point(&current);
+        }
+    }
+}
+
+/// How moves are shared among several carriers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Schedule {
+    /// Carriers take a move each in turn.
+    RoundRobin { carriers: usize },
+    /// Carriers take `size` moves each in turn.
+    Blocks { carriers: usize, size: usize },
+    /// Carrier `i` takes `weights[i]` moves in its turn.
+    Weighted(Vec<usize>),
+}
+
+impl Schedule {
+    pub fn carriers(&self) -> usize {
+        match self {
+            Schedule::RoundRobin { carriers } | Schedule::Blocks { carriers, .. } => *carriers,
+            Schedule::Weighted(weights) => weights.len(),
+        }
+    }
+
+    /// Carrier, which takes move number `i`.
+    /// None if nobody takes moves, e.g. there are no carriers.
+    pub fn carrier_of(&self, i: usize) -> Option<usize> {
+        match self {
+            &Schedule::RoundRobin { carriers } => i.checked_rem(carriers),
+            &Schedule::Blocks { carriers, size } => (i.checked_div(size)?).checked_rem(carriers),
+            Schedule::Weighted(weights) => {
+                let mut turn = i.checked_rem(weights.iter().sum())?;
+                weights.iter().position(|&weight| {
+                    let takes = turn < weight;
+                    turn = turn.saturating_sub(weight);
+                    takes
+                })
+            }
+        }
+    }
+}
+
+/// Several carriers, which visits every point in a path according to moves,
+/// which are taken in turn from common moves list.
+pub struct TurnCarriers<'a> {
+    start_point: Point,
+    moves: &'a Moves,
+    schedule: Schedule,
+}
+
+impl<'a> TurnCarriers<'a> {
+    /// Carriers taking moves round-robin.
+    pub fn new(start_point: Point, moves: &'a Moves, num_of_carriers: usize) -> Self {
+        Self::with_schedule(
+            start_point,
+            moves,
+            Schedule::RoundRobin {
+                carriers: num_of_carriers,
+            },
+        )
+    }
+
+    pub fn with_schedule(start_point: Point, moves: &'a Moves, schedule: Schedule) -> Self {
+        Self {
+            start_point,
+            moves,
+            schedule,
+        }
+    }
+}
+
+impl<'a> Carrier for TurnCarriers<'a> {
+    fn visit(self, delivery_map: &mut DeliveryMap) {
+        let mut carriers = vec![self.start_point; self.schedule.carriers()];
+        for current in &carriers {
+            delivery_map.visit_point(current);
+        }
+        for (i, mv) in self.moves.iter().enumerate() {
+            let Some(carrier) = self.schedule.carrier_of(i) else {
+                break;
+            };
+            let current = &mut carriers[carrier];
+            *current += mv;
+            delivery_map.visit_point(current);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::delivered;
+
+    #[test]
+    fn single_carrier_walks_diagonals() {
+        let moves = Moves::from_compass("NE NE SW").unwrap();
+        let map = DeliveryMap::new().visit_by(SingleCarrier::new(Point::ORIGIN, &moves));
+        assert_eq!(map.visits(&Point::new(1, 1)), 2);
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn round_robin() {
+        let schedule = Schedule::RoundRobin { carriers: 3 };
+        let taken = (0..6).map(|i| schedule.carrier_of(i).unwrap());
+        assert!(taken.eq([0, 1, 2, 0, 1, 2]));
+    }
+
+    #[test]
+    fn blocks() {
+        let schedule = Schedule::Blocks {
+            carriers: 2,
+            size: 2,
+        };
+        let taken = (0..6).map(|i| schedule.carrier_of(i).unwrap());
+        assert!(taken.eq([0, 0, 1, 1, 0, 0]));
+    }
+
+    #[test]
+    fn weighted() {
+        let schedule = Schedule::Weighted(vec![2, 0, 1]);
+        assert_eq!(schedule.carriers(), 3);
+        let taken = (0..6).map(|i| schedule.carrier_of(i).unwrap());
+        assert!(taken.eq([0, 0, 2, 0, 0, 2]));
+    }
+
+    #[test]
+    fn nobody_to_take_moves() {
+        assert_eq!(Schedule::RoundRobin { carriers: 0 }.carrier_of(0), None);
+        assert_eq!(Schedule::Weighted(vec![0]).carrier_of(0), None);
+        assert!(delivered("^>", Schedule::Weighted(vec![])).is_empty());
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use crate::{carrier::Carrier, moves::Point};
+
+#[derive(Default)]
+pub struct DeliveryMap(HashMap<Point, u32>);
+
+impl DeliveryMap {
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    pub fn visit_by<C: Carrier>(mut self, carrier: C) -> Self {
+        carrier.visit(&mut self);
+        self
+    }
+
+    pub fn visit_point(&mut self, point: &Point) {
+        self.0.entry(*point).and_modify(|x| *x += 1).or_insert(1);
+    }
+
+    /// How many times the house at `point` was visited.
+    pub fn visits(&self, point: &Point) -> u32 {
+        self.0.get(point).copied().unwrap_or_default()
+    }
+
+    /// Visited houses with their visit counts, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Point, u32)> {
+        self.0.iter().map(|(point, &visits)| (point, visits))
+    }
+
+    /// House visited the most times. Of equally visited ones, any may be returned.
+    pub fn most_visited(&self) -> Option<(&Point, u32)> {
+        self.iter().max_by_key(|&(_, visits)| visits)
+    }
+
+    /// Lower left and upper right corners of the visited area.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.0.keys();
+        let first = *points.next()?;
+        Some(points.fold((first, first), |(min, max), p| {
+            (
+                Point {
+                    x: min.x.min(p.x),
+                    y: min.y.min(p.y),
+                },
+                Point {
+                    x: max.x.max(p.x),
+                    y: max.y.max(p.y),
+                },
+            )
+        }))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+pub struct DeliveryReport {
+    name: String,
+    delivery_map: DeliveryMap,
+}
+
+impl DeliveryReport {
+    pub fn new(name: String, delivery_map: DeliveryMap) -> Self {
+        Self { name, delivery_map }
+    }
+
+    pub fn print_visited_houses(&self) {
+        println!("{} visited houses: {}", self.name, self.delivery_map.len());
+        if let Some((point, visits)) = self.delivery_map.most_visited() {
+            println!(
+                "{} most visited house: ({}, {}), {visits} times",
+                self.name, point.x, point.y
+            );
+        }
+    }
+
+    pub fn delivery_map(&self) -> &DeliveryMap {
+        &self.delivery_map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{carrier::Schedule, test_util::delivered};
+
+    #[test]
+    fn visit_counts() {
+        let map = delivered(
+            "^>v<",
+            Schedule::Blocks {
+                carriers: 2,
+                size: 2,
+            },
+        );
+        // Both carriers start at the origin, then walk ^> and v< apart.
+        assert_eq!(map.visits(&Point::ORIGIN), 2);
+        assert_eq!(map.visits(&Point { x: 1, y: 1 }), 1);
+        assert_eq!(map.visits(&Point { x: 5, y: 5 }), 0);
+        assert_eq!(map.len(), 5);
+        assert_eq!(map.most_visited(), Some((&Point::ORIGIN, 2)));
+        assert_eq!(
+            map.bounds(),
+            Some((Point { x: -1, y: -1 }, Point { x: 1, y: 1 }))
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use helpers::{AocError, Solution};
+
+pub mod carrier;
+pub mod delivery;
+pub mod moves;
+pub mod render;
+#[cfg(test)]
+mod test_util;
+
+pub use carrier::{Carrier, Schedule, SingleCarrier, TurnCarriers};
+pub use delivery::{DeliveryMap, DeliveryReport};
+pub use moves::{Move, MoveError, Moves, Point};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 3;
+
+    type Input = Moves;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> std::result::Result<Self::Input, AocError> {
+        Moves::try_from(input.trim()).map_err(AocError::parse)
+    }
+
+    fn part1(moves: &Self::Input) -> std::result::Result<Self::Answer1, AocError> {
+        Ok(visited_houses(SingleCarrier::new(Point::ORIGIN, moves)))
+    }
+
+    fn part2(moves: &Self::Input) -> std::result::Result<Self::Answer2, AocError> {
+        Ok(visited_houses(TurnCarriers::new(Point::ORIGIN, moves, 2)))
+    }
+}
+
+/// Number of houses, which got at least one present.
+pub fn visited_houses(carrier: impl Carrier) -> usize {
+    DeliveryMap::new().visit_by(carrier).len()
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+
+    helpers::example_tests!(Day3; square, back_and_forth);
+}
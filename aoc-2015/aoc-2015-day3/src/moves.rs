@@ -0,0 +1,262 @@
+use std::{fs::File, io::Read, str::FromStr};
+
+#[derive(Debug, thiserror::Error)]
+pub enum MoveError {
+    #[error("invalid move '{found}' at {offset}, expected one of ^v<>")]
+    InvalidMove { offset: usize, found: char },
+    #[error("invalid direction '{0}', expected one of N NE E SE S SW W NW")]
+    InvalidDirection(String),
+    #[error("can't read moves: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Move to one of 8 neighbouring points. Up is north.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Move {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Move {
+    /// Moves along the axes, as in `^v<>` notation.
+    pub const STRAIGHT: [Move; 4] = [Move::Up, Move::Down, Move::Left, Move::Right];
+    pub const DIAGONAL: [Move; 4] = [Move::UpLeft, Move::UpRight, Move::DownLeft, Move::DownRight];
+
+    /// Change of `x` and `y` coordinates.
+    pub fn delta(self) -> (i32, i32) {
+        match self {
+            Move::Up => (0, 1),
+            Move::Down => (0, -1),
+            Move::Left => (-1, 0),
+            Move::Right => (1, 0),
+            Move::UpLeft => (-1, 1),
+            Move::UpRight => (1, 1),
+            Move::DownLeft => (-1, -1),
+            Move::DownRight => (1, -1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        Move::DIAGONAL.contains(&self)
+    }
+
+    /// Straight move in `^v<>` notation.
+    pub fn from_arrow(arrow: char) -> Option<Self> {
+        match arrow {
+            '^' => Some(Move::Up),
+            'v' => Some(Move::Down),
+            '<' => Some(Move::Left),
+            '>' => Some(Move::Right),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Move {
+    type Err = MoveError;
+
+    /// Parses a compass direction, e.g. `N` or `sw`, north being up.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "N" => Ok(Move::Up),
+            "S" => Ok(Move::Down),
+            "W" => Ok(Move::Left),
+            "E" => Ok(Move::Right),
+            "NW" => Ok(Move::UpLeft),
+            "NE" => Ok(Move::UpRight),
+            "SW" => Ok(Move::DownLeft),
+            "SE" => Ok(Move::DownRight),
+            _ => Err(MoveError::InvalidDirection(s.to_owned())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Moves(Vec<Move>);
+
+impl Moves {
+    /// Parses compass directions separated by commas or whitespace, e.g. `N, NE, e`.
+    pub fn from_compass(s: &str) -> Result<Self, MoveError> {
+        s.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|direction| !direction.is_empty())
+            .map(str::parse)
+            .collect::<Result<Vec<Move>, _>>()
+            .map(Moves)
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Move> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl TryFrom<&str> for Moves {
+    type Error = MoveError;
+
+    /// Parses straight moves in `^v<>` notation.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let moves = value
+            .char_indices()
+            .map(|(offset, found)| {
+                Move::from_arrow(found).ok_or(MoveError::InvalidMove { offset, found })
+            })
+            .collect::<Result<Vec<Move>, _>>()?;
+        Ok(Moves(moves))
+    }
+}
+
+impl TryFrom<File> for Moves {
+    type Error = MoveError;
+
+    fn try_from(mut file: File) -> Result<Self, Self::Error> {
+        let mut contents: String = String::new();
+        file.read_to_string(&mut contents)?;
+        Moves::try_from(contents.as_str())
+    }
+}
+
+impl From<Vec<Move>> for Moves {
+    fn from(value: Vec<Move>) -> Self {
+        Self(value)
+    }
+}
+
+impl AsRef<[Move]> for Moves {
+    fn as_ref(&self) -> &[Move] {
+        &self.0
+    }
+}
+
+impl<'a> IntoIterator for &'a Moves {
+    type Item = &'a Move;
+    type IntoIter = std::slice::Iter<'a, Move>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// All 8 points around, straight ones first.
+    pub fn neighbours(self) -> impl Iterator<Item = Point> {
+        Move::STRAIGHT
+            .into_iter()
+            .chain(Move::DIAGONAL)
+            .map(move |mv| self + &mv)
+    }
+}
+
+impl std::ops::Add<&Move> for Point {
+    type Output = Self;
+
+    fn add(self, rhs: &Move) -> Self::Output {
+        let (dx, dy) = rhs.delta();
+        Point {
+            x: self.x + dx,
+            y: self.y + dy,
+        }
+    }
+}
+
+impl std::ops::AddAssign<&Move> for Point {
+    fn add_assign(&mut self, rhs: &Move) {
+        *self = *self + rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_arrows() {
+        let moves = Moves::try_from("^>v<").unwrap();
+        assert_eq!(
+            moves.as_ref(),
+            [Move::Up, Move::Right, Move::Down, Move::Left]
+        );
+    }
+
+    #[test]
+    fn invalid_arrow() {
+        let err = Moves::try_from("^>x<").unwrap_err();
+        assert!(matches!(
+            err,
+            MoveError::InvalidMove {
+                offset: 2,
+                found: 'x'
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "invalid move 'x' at 2, expected one of ^v<>"
+        );
+    }
+
+    #[test]
+    fn parse_compass() {
+        let moves = Moves::from_compass("N, ne,E SE\nS,SW , W,NW").unwrap();
+        assert_eq!(
+            moves.as_ref(),
+            [
+                Move::Up,
+                Move::UpRight,
+                Move::Right,
+                Move::DownRight,
+                Move::Down,
+                Move::DownLeft,
+                Move::Left,
+                Move::UpLeft
+            ]
+        );
+        assert!(matches!(
+            Moves::from_compass("N,NNE"),
+            Err(MoveError::InvalidDirection(direction)) if direction == "NNE"
+        ));
+    }
+
+    #[test]
+    fn diagonal_moves() {
+        let mut point = Point::ORIGIN;
+        for mv in &Moves::from_compass("NE NE SE W").unwrap() {
+            point += mv;
+        }
+        assert_eq!(point, Point::new(2, 1));
+        assert!(Move::DownLeft.is_diagonal());
+        assert!(!Move::Down.is_diagonal());
+    }
+
+    #[test]
+    fn neighbours() {
+        let neighbours = Point::new(1, 1).neighbours().collect::<Vec<_>>();
+        assert_eq!(neighbours.len(), 8);
+        assert!(neighbours.contains(&Point::new(0, 0)));
+        assert!(neighbours.contains(&Point::new(2, 1)));
+        assert!(!neighbours.contains(&Point::new(1, 1)));
+    }
+}
@@ -0,0 +1,108 @@
+//! Pictures of the visited area, north up.
+
+use std::io::{self, Write};
+
+use crate::{DeliveryMap, Point};
+
+/// Draws a character per house: `.` for not visited, `1`-`9` for visit counts, `+` for more.
+pub fn ascii(delivery_map: &DeliveryMap, mut w: impl Write) -> io::Result<()> {
+    let Some((min, max)) = delivery_map.bounds() else {
+        return Ok(());
+    };
+    for y in (min.y..=max.y).rev() {
+        let row = (min.x..=max.x)
+            .map(|x| match delivery_map.visits(&Point { x, y }) {
+                0 => '.',
+                visits @ 1..=9 => char::from_digit(visits, 10).expect("visits is a digit"),
+                _ => '+',
+            })
+            .collect::<String>();
+        writeln!(w, "{row}")?;
+    }
+    Ok(())
+}
+
+/// Draws a binary PPM heatmap, a pixel per house:
+/// black for not visited, from red for a single visit to yellow for the most visited.
+pub fn ppm(delivery_map: &DeliveryMap, mut w: impl Write) -> io::Result<()> {
+    let Some((min, max)) = delivery_map.bounds() else {
+        return write!(w, "P6\n0 0\n255\n");
+    };
+    let (width, height) = (max.x.abs_diff(min.x) + 1, max.y.abs_diff(min.y) + 1);
+    write!(w, "P6\n{width} {height}\n255\n")?;
+
+    // Visit counts are spread unevenly, so they are scaled logarithmically.
+    let most = delivery_map.most_visited().map_or(1, |(_, visits)| visits);
+    let scale = f64::from(most).ln();
+    let mut row = Vec::with_capacity(width as usize * 3);
+    for y in (min.y..=max.y).rev() {
+        row.clear();
+        for x in min.x..=max.x {
+            let pixel = match delivery_map.visits(&Point { x, y }) {
+                0 => [0, 0, 0],
+                visits => {
+                    let heat = match visits {
+                        1 => 0.0,
+                        _ => f64::from(visits).ln() / scale,
+                    };
+                    [255, (heat * 255.0).round() as u8, 0]
+                }
+            };
+            row.extend(pixel);
+        }
+        w.write_all(&row)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::delivered_alone;
+
+    #[test]
+    fn ascii_map() {
+        let mut out = Vec::new();
+        ascii(&delivered_alone("^>v<<"), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), ".11\n121\n");
+    }
+
+    #[test]
+    fn ascii_many_visits() {
+        let mut out = Vec::new();
+        ascii(&delivered_alone(&"><".repeat(9)), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "+9\n");
+    }
+
+    #[test]
+    fn ppm_heatmap() {
+        let mut out = Vec::new();
+        ppm(&delivered_alone("^>v"), &mut out).unwrap();
+        let (header, pixels) = out.split_at(b"P6\n2 2\n255\n".len());
+        assert_eq!(header, b"P6\n2 2\n255\n");
+        // (0, 1) (1, 1)
+        // (0, 0) (1, 0)
+        #[rustfmt::skip]
+        assert_eq!(pixels, [
+            255, 0, 0,   255, 0, 0,
+            255, 0, 0,   255, 0, 0,
+        ]);
+
+        let mut out = Vec::new();
+        ppm(&delivered_alone(">^<v>>><<"), &mut out).unwrap();
+        // Bottom row is visited 2, 3, 2 and 1 times.
+        assert_eq!(
+            &out[out.len() - 12..],
+            [255, 161, 0, 255, 255, 0, 255, 161, 0, 255, 0, 0]
+        );
+    }
+
+    #[test]
+    fn empty_map() {
+        let mut out = Vec::new();
+        ascii(&DeliveryMap::new(), &mut out).unwrap();
+        assert!(out.is_empty());
+        ppm(&DeliveryMap::new(), &mut out).unwrap();
+        assert_eq!(out, b"P6\n0 0\n255\n");
+    }
+}
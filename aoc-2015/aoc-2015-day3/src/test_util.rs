@@ -0,0 +1,15 @@
+//! Helpers shared by tests of every module.
+
+use crate::{DeliveryMap, Moves, Point, Schedule, SingleCarrier, TurnCarriers};
+
+/// Houses visited from the origin by carriers taking turns by `schedule`.
+pub fn delivered(moves: &str, schedule: Schedule) -> DeliveryMap {
+    let moves = Moves::try_from(moves).unwrap();
+    DeliveryMap::new().visit_by(TurnCarriers::with_schedule(Point::ORIGIN, &moves, schedule))
+}
+
+/// Houses visited from the origin by a single carrier.
+pub fn delivered_alone(moves: &str) -> DeliveryMap {
+    let moves = Moves::try_from(moves).unwrap();
+    DeliveryMap::new().visit_by(SingleCarrier::new(Point::ORIGIN, &moves))
+}
@@ -0,0 +1,55 @@
+//! Hash algorithms to mine with, each behind a cargo feature of its name.
+
+/// Hash algorithm, which digests a whole message at once.
+pub trait HashAlgorithm {
+    type Digest: AsRef<[u8]>;
+
+    fn digest(data: &[u8]) -> Self::Digest;
+}
+
+#[cfg(feature = "md5")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Md5;
+
+#[cfg(feature = "md5")]
+impl HashAlgorithm for Md5 {
+    type Digest = [u8; 16];
+
+    fn digest(data: &[u8]) -> Self::Digest {
+        md5::compute(data).0
+    }
+}
+
+#[cfg(feature = "sha256")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sha256;
+
+#[cfg(feature = "sha256")]
+impl HashAlgorithm for Sha256 {
+    type Digest = [u8; 32];
+
+    fn digest(data: &[u8]) -> Self::Digest {
+        use sha2::Digest;
+
+        sha2::Sha256::digest(data).into()
+    }
+}
+
+#[cfg(all(test, any(feature = "md5", feature = "sha256")))]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "md5")]
+    #[test]
+    fn md5() {
+        let digest = Md5::digest(b"abcdef609043");
+        assert_eq!(digest[..3], [0x00, 0x00, 0x01]);
+    }
+
+    #[cfg(feature = "sha256")]
+    #[test]
+    fn sha256() {
+        let digest = Sha256::digest(b"abc");
+        assert_eq!(digest[..4], [0xba, 0x78, 0x16, 0xbf]);
+    }
+}
@@ -0,0 +1,216 @@
+use std::{io::Write, marker::PhantomData, ops::RangeInclusive};
+
+use hash::HashAlgorithm;
+#[cfg(feature = "md5")]
+use helpers::{AocError, Solution};
+#[cfg(feature = "md5")]
+use parallel::ParallelMiner;
+use target::Target;
+
+pub mod hash;
+pub mod parallel;
+pub mod target;
+
+#[cfg(feature = "md5")]
+pub struct Day4;
+
+#[cfg(feature = "md5")]
+impl Solution for Day4 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 4;
+
+    type Input = String;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let key = input.trim();
+        if key.is_empty() {
+            return Err(AocError::parse("expected secret key, got empty input"));
+        }
+        Ok(key.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, AocError> {
+        mine(input, Target::zero_nibbles(5))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, AocError> {
+        mine(input, Target::zero_nibbles(6))
+    }
+}
+
+#[cfg(feature = "md5")]
+fn mine(input: &str, target: Target) -> Result<String, AocError> {
+    ParallelMiner::<hash::Md5>::new(target)
+        .mine(input)
+        .ok_or_else(|| AocError::compute("no matching number found"))
+}
+
+pub trait Miner {
+    fn mine(&self, input: &str) -> Option<String>;
+}
+
+/// Input followed by a nonce, rewritten in place for every nonce.
+#[derive(Debug, Clone)]
+pub struct NonceBuffer {
+    buf: Vec<u8>,
+    input_len: usize,
+}
+
+impl NonceBuffer {
+    pub fn new(input: &str) -> Self {
+        let mut buf = Vec::with_capacity(input.len() + u64::MAX.ilog10() as usize + 1);
+        buf.extend_from_slice(input.as_bytes());
+        Self {
+            buf,
+            input_len: input.len(),
+        }
+    }
+
+    /// Input followed by decimal `nonce`.
+    pub fn with_nonce(&mut self, nonce: u64) -> &[u8] {
+        self.buf.truncate(self.input_len);
+        write!(self.buf, "{nonce}").expect("buffer has room for any nonce");
+        &self.buf
+    }
+}
+
+/// Miner, which hashes input followed by nonces with `H`, until a digest hits the target.
+#[derive(Debug, Clone)]
+pub struct HashMiner<H> {
+    pub target: Target,
+    hash: PhantomData<fn() -> H>,
+}
+
+#[cfg(feature = "md5")]
+pub type Md5Miner = HashMiner<hash::Md5>;
+
+#[cfg(feature = "sha256")]
+pub type Sha256Miner = HashMiner<hash::Sha256>;
+
+impl<H: HashAlgorithm> HashMiner<H> {
+    pub fn new(target: Target) -> Self {
+        Self {
+            target,
+            hash: PhantomData,
+        }
+    }
+
+    /// Whether the digest of buffered input followed by `nonce` hits the target.
+    pub fn is_match(&self, buf: &mut NonceBuffer, nonce: u64) -> bool {
+        self.target
+            .matches(H::digest(buf.with_nonce(nonce)).as_ref())
+    }
+
+    /// Every hit with nonces from 1 to `u32::MAX`, in order.
+    pub fn matches(&self, input: &str) -> Matches<'_, H> {
+        self.matches_in(input, 1..=u32::MAX as u64)
+    }
+
+    pub fn matches_in(&self, input: &str, nonces: RangeInclusive<u64>) -> Matches<'_, H> {
+        Matches {
+            miner: self,
+            buf: NonceBuffer::new(input),
+            nonces,
+        }
+    }
+}
+
+impl<H: HashAlgorithm> Miner for HashMiner<H> {
+    fn mine(&self, input: &str) -> Option<String> {
+        self.matches(input)
+            .next()
+            .map(|(nonce, _)| nonce.to_string())
+    }
+}
+
+/// Iterator over `(nonce, digest)` of every hit, see [`HashMiner::matches`].
+pub struct Matches<'a, H> {
+    miner: &'a HashMiner<H>,
+    buf: NonceBuffer,
+    nonces: RangeInclusive<u64>,
+}
+
+impl<'a, H: HashAlgorithm> Iterator for Matches<'a, H> {
+    type Item = (u64, H::Digest);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for nonce in self.nonces.by_ref() {
+            let digest = H::digest(self.buf.with_nonce(nonce));
+            if self.miner.target.matches(digest.as_ref()) {
+                return Some((nonce, digest));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nonce_buffer() {
+        let mut buf = NonceBuffer::new("abc");
+        assert_eq!(buf.with_nonce(12345), b"abc12345");
+        assert_eq!(buf.with_nonce(7), b"abc7");
+        assert_eq!(buf.with_nonce(u64::MAX), b"abc18446744073709551615");
+    }
+
+    #[cfg(feature = "md5")]
+    #[test]
+    fn empty_key() {
+        assert!(Day4::parse(" \n").is_err());
+        assert_eq!(Day4::parse("abcdef\n").unwrap(), "abcdef");
+    }
+
+    #[cfg(feature = "md5")]
+    #[test]
+    fn md5_miner() {
+        let miner = Md5Miner::new("000".parse().unwrap());
+        assert_eq!(miner.mine("abcdef").as_deref(), Some("3337"));
+    }
+
+    #[cfg(feature = "md5")]
+    #[test]
+    fn matches_in_order() {
+        let miner = Md5Miner::new(Target::zero_nibbles(3));
+        let hits = miner.matches("abcdef").take(3).collect::<Vec<_>>();
+        assert_eq!(hits[0].0, 3337);
+        assert!(hits.windows(2).all(|w| w[0].0 < w[1].0));
+        for (nonce, digest) in &hits {
+            assert_eq!(*digest, md5::compute(format!("abcdef{nonce}")).0);
+        }
+
+        let in_range = miner
+            .matches_in("abcdef", hits[0].0 + 1..=hits[2].0)
+            .map(|(nonce, _)| nonce);
+        assert!(in_range.eq([hits[1].0, hits[2].0]));
+        assert_eq!(miner.matches_in("abcdef", 1..=3336).next(), None);
+    }
+
+    #[cfg(feature = "sha256")]
+    #[test]
+    fn sha256_miner() {
+        use hash::Sha256;
+
+        let target = Target::zero_nibbles(3);
+        let nonce: u64 = Sha256Miner::new(target.clone())
+            .mine("abcdef")
+            .unwrap()
+            .parse()
+            .unwrap();
+        let hits =
+            |nonce: u64| target.matches(&Sha256::digest(format!("abcdef{nonce}").as_bytes()));
+        assert!(hits(nonce));
+        assert!(!(1..nonce).any(hits));
+    }
+}
+
+#[cfg(all(test, feature = "md5"))]
+mod examples {
+    use super::*;
+
+    helpers::example_tests!(Day4; abcdef, pqrstuv);
+}
@@ -1,35 +1,21 @@
-fn main() {
-    let input = std::env::args().skip(1).next().unwrap();
+use aoc_2015_day4::{hash::Md5, parallel::ParallelMiner, target::Target, Day4};
 
-    for (i, wanted_prefix) in ["00000", "000000"].into_iter().enumerate() {
-        let got = Md5Miner { wanted_prefix }.mine(&input);
-        println!("Part {i} answer: {got:?}", i = i + 1);
-    }
-}
-
-trait Miner {
-    fn mine(&self, input: &str) -> Option<String>;
-}
-
-struct Md5Miner<'a> {
-    wanted_prefix: &'a str,
-}
-
-impl<'a> Md5Miner<'a> {
-    fn hash(&self, input: &'a str) -> String {
-        format!("{:x}", md5::compute(input))
-    }
-}
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let input = helpers::input::from_args::<Day4>()?;
+    let input = input.trim();
 
-impl<'a> Miner for Md5Miner<'a> {
-    fn mine(&self, input: &str) -> Option<String> {
-        for i in 1..std::u32::MAX {
-            let s = format!("{}{}", input, i);
-            let hash = self.hash(&s);
-            if hash.starts_with(&self.wanted_prefix) {
-                return Some(i.to_string());
-            }
+    for (i, zeros) in [5, 6].into_iter().enumerate() {
+        let report = ParallelMiner::<Md5>::new(Target::zero_nibbles(zeros)).search(input);
+        match report.nonce {
+            Some(nonce) => println!("Part {} answer: {nonce}", i + 1),
+            None => println!("Part {} answer: not found", i + 1),
         }
-        None
+        println!(
+            "  {} hashes in {:.2?}, {:.0} hashes/s",
+            report.hashes,
+            report.elapsed,
+            report.hashes_per_sec()
+        );
     }
+    Ok(())
 }
@@ -0,0 +1,200 @@
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+use crate::{hash::HashAlgorithm, target::Target, HashMiner, Miner, NonceBuffer};
+
+/// Nonces a worker takes at once.
+const CHUNK: u64 = 4096;
+
+/// Miner, which splits nonces over worker threads.
+///
+/// Workers take chunks of nonces in increasing order, so once a nonce is found,
+/// only chunks below it are left to search, and the lowest one is returned.
+#[derive(Debug, Clone)]
+pub struct ParallelMiner<H> {
+    miner: HashMiner<H>,
+    start: u64,
+    limit: u64,
+    threads: usize,
+}
+
+/// Result of a search, with its speed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MineReport {
+    /// Lowest matching nonce.
+    pub nonce: Option<u64>,
+    /// Number of nonces hashed by all workers.
+    pub hashes: u64,
+    pub elapsed: Duration,
+}
+
+impl MineReport {
+    pub fn hashes_per_sec(&self) -> f64 {
+        self.hashes as f64 / self.elapsed.as_secs_f64()
+    }
+}
+
+impl<H: HashAlgorithm> ParallelMiner<H> {
+    /// Searches nonces from 1 to `u32::MAX` with a worker per CPU.
+    pub fn new(target: Target) -> Self {
+        Self {
+            miner: HashMiner::new(target),
+            start: 1,
+            limit: u32::MAX as u64,
+            threads: std::thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        }
+    }
+
+    pub fn with_start(mut self, start: u64) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// The last nonce to try. `u64::MAX` itself is never tried, as it marks no match.
+    pub fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = limit.min(u64::MAX - 1);
+        self
+    }
+
+    /// Number of workers, at least one.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    pub fn search(&self, input: &str) -> MineReport {
+        self.search_from(input, self.start)
+    }
+
+    fn search_from(&self, input: &str, start: u64) -> MineReport {
+        let started = Instant::now();
+        let next = AtomicU64::new(start);
+        let found = AtomicU64::new(u64::MAX);
+        let hashes = AtomicU64::new(0);
+
+        let work = || {
+            let mut buf = NonceBuffer::new(input);
+            loop {
+                // Past the limit `next` stays put, and the limit is below
+                // `u64::MAX`, so it never wraps around.
+                let Ok(from) = next.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |from| {
+                    (from <= self.limit).then(|| from.saturating_add(CHUNK))
+                }) else {
+                    return;
+                };
+                if from >= found.load(Ordering::Relaxed) {
+                    return;
+                }
+                let to = from.saturating_add(CHUNK - 1).min(self.limit);
+                let mut tried = 0;
+                let hit = (from..=to).find(|&nonce| {
+                    tried += 1;
+                    self.miner.is_match(&mut buf, nonce)
+                });
+                hashes.fetch_add(tried, Ordering::Relaxed);
+                if let Some(nonce) = hit {
+                    found.fetch_min(nonce, Ordering::Relaxed);
+                    return;
+                }
+            }
+        };
+        std::thread::scope(|s| {
+            for _ in 0..self.threads {
+                s.spawn(work);
+            }
+        });
+
+        let nonce = found.into_inner();
+        MineReport {
+            nonce: (nonce != u64::MAX).then_some(nonce),
+            hashes: hashes.into_inner(),
+            elapsed: started.elapsed(),
+        }
+    }
+}
+
+impl<H: HashAlgorithm> ParallelMiner<H> {
+    /// Every hit from the start up to the limit, in order.
+    /// Each one is the lowest hit of a new search, starting right after the previous one.
+    pub fn matches<'a>(&'a self, input: &'a str) -> impl Iterator<Item = (u64, H::Digest)> + 'a {
+        let mut start = self.start;
+        std::iter::from_fn(move || {
+            let nonce = self.search_from(input, start).nonce?;
+            start = nonce + 1;
+            Some((nonce, H::digest(NonceBuffer::new(input).with_nonce(nonce))))
+        })
+    }
+}
+
+impl<H: HashAlgorithm> Miner for ParallelMiner<H> {
+    fn mine(&self, input: &str) -> Option<String> {
+        self.search(input).nonce.map(|nonce| nonce.to_string())
+    }
+}
+
+#[cfg(all(test, feature = "md5"))]
+mod tests {
+    use super::*;
+    use crate::{hash::Md5, Md5Miner};
+
+    type ParallelMiner = super::ParallelMiner<Md5>;
+
+    #[test]
+    fn finds_lowest_nonce() {
+        for threads in [1, 3] {
+            let report = ParallelMiner::new(Target::zero_nibbles(3))
+                .with_threads(threads)
+                .search("abcdef");
+            assert_eq!(report.nonce, Some(3337));
+            assert!(report.hashes >= 3337);
+        }
+    }
+
+    #[test]
+    fn same_as_single_threaded() {
+        let target = Target::zero_nibbles(4);
+        let miner = ParallelMiner::new(target.clone()).with_threads(4);
+        assert_eq!(miner.mine("pqrstuv"), Md5Miner::new(target).mine("pqrstuv"));
+    }
+
+    #[test]
+    fn matches_same_as_single_threaded() {
+        let target = Target::zero_nibbles(3);
+        let miner = ParallelMiner::new(target.clone())
+            .with_threads(3)
+            .with_limit(20_000);
+        let hits = miner.matches("abcdef").collect::<Vec<_>>();
+        assert!(!hits.is_empty());
+        assert_eq!(
+            hits,
+            Md5Miner::new(target)
+                .matches_in("abcdef", 1..=20_000)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn start_and_limit() {
+        let miner = ParallelMiner::new(Target::zero_nibbles(3)).with_threads(2);
+        let nonce = miner.clone().with_start(3338).search("abcdef").nonce;
+        assert!(nonce.unwrap() > 3337);
+
+        let report = miner.with_limit(3336).search("abcdef");
+        assert_eq!(report.nonce, None);
+        assert_eq!(report.hashes, 3336);
+    }
+
+    #[test]
+    fn near_u64_max() {
+        let report = ParallelMiner::new(Target::zero_nibbles(8))
+            .with_threads(4)
+            .with_start(u64::MAX - 5000)
+            .with_limit(u64::MAX)
+            .search("abcdef");
+        assert_eq!(report.nonce, None);
+        assert_eq!(report.hashes, 5000);
+    }
+}
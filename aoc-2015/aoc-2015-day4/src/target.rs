@@ -0,0 +1,110 @@
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TargetError {
+    #[error("invalid hex digit '{0}' in target prefix")]
+    InvalidHexDigit(char),
+}
+
+/// Leading bits a raw digest must have, e.g. 20 zero bits or hex prefix `00000`.
+///
+/// Digest is compared byte by byte under a mask, so it never has to be formatted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Target {
+    value: Vec<u8>,
+    mask: Vec<u8>,
+}
+
+impl Target {
+    pub fn zero_bits(bits: u32) -> Self {
+        let mask = (0..bits.div_ceil(8))
+            .map(|i| match bits - i * 8 {
+                8.. => 0xff,
+                left => !(0xff >> left),
+            })
+            .collect::<Vec<_>>();
+        Self {
+            value: vec![0; mask.len()],
+            mask,
+        }
+    }
+
+    /// Zero hex digits, as the puzzle asks.
+    pub fn zero_nibbles(nibbles: u32) -> Self {
+        Self::zero_bits(nibbles * 4)
+    }
+
+    /// Digest, which hex form starts with `prefix`, case-insensitive.
+    pub fn hex_prefix(prefix: &str) -> Result<Self, TargetError> {
+        let mut target = Self {
+            value: Vec::new(),
+            mask: Vec::new(),
+        };
+        for (i, c) in prefix.chars().enumerate() {
+            let nibble = c.to_digit(16).ok_or(TargetError::InvalidHexDigit(c))? as u8;
+            if i % 2 == 0 {
+                target.value.push(nibble << 4);
+                target.mask.push(0xf0);
+            } else {
+                *target.value.last_mut().expect("high nibble is pushed") |= nibble;
+                *target.mask.last_mut().expect("high nibble is pushed") = 0xff;
+            }
+        }
+        Ok(target)
+    }
+
+    /// Digests shorter than the target never match.
+    pub fn matches(&self, digest: &[u8]) -> bool {
+        digest.len() >= self.mask.len()
+            && self
+                .mask
+                .iter()
+                .zip(&self.value)
+                .zip(digest)
+                .all(|((mask, value), byte)| byte & mask == *value)
+    }
+}
+
+impl FromStr for Target {
+    type Err = TargetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::hex_prefix(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_bits() {
+        let target = Target::zero_bits(12);
+        assert!(target.matches(&[0x00, 0x0f, 0xff]));
+        assert!(!target.matches(&[0x00, 0x10, 0x00]));
+        assert!(!target.matches(&[0x00]));
+        assert!(Target::zero_bits(0).matches(&[0xff]));
+    }
+
+    #[test]
+    fn zero_nibbles() {
+        assert_eq!(
+            Target::zero_nibbles(5),
+            Target::hex_prefix("00000").unwrap()
+        );
+        assert!(Target::zero_nibbles(5).matches(&[0x00, 0x00, 0x0a, 0xbc]));
+        assert!(!Target::zero_nibbles(6).matches(&[0x00, 0x00, 0x0a, 0xbc]));
+    }
+
+    #[test]
+    fn hex_prefix() {
+        let target: Target = "abC".parse().unwrap();
+        assert!(target.matches(&[0xab, 0xc0]));
+        assert!(target.matches(&[0xab, 0xcf, 0x12]));
+        assert!(!target.matches(&[0xab, 0xd0]));
+        assert_eq!(
+            "0g".parse::<Target>(),
+            Err(TargetError::InvalidHexDigit('g'))
+        );
+    }
+}
@@ -0,0 +1,477 @@
+//! Engine, which checks all rules in one pass over the bytes of a string.
+//!
+//! Rules are compiled into probes, each updated for every byte, and substrings of
+//! all `contains` rules are matched at once by an Aho-Corasick automaton.
+//! Strings outside ASCII are decoded first, so probes see chars as [`Rule`] does.
+
+use std::{collections::HashMap, num::NonZeroUsize, ops::Range};
+
+use crate::{
+    nice::Checker,
+    rules::{Cmp, Rule},
+};
+
+/// Compiled rule, see the [module](self) docs.
+#[derive(Debug, Clone)]
+pub struct Engine {
+    probes: Vec<Probe>,
+    tree: Node,
+    matcher: Matcher,
+    threads: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Probe {
+    Count {
+        /// ASCII letters.
+        letters: Box<[bool; 128]>,
+        /// Letters outside ASCII, only found in strings outside ASCII.
+        others: Vec<char>,
+        cmp: Cmp,
+        n: usize,
+    },
+    Repeat(usize),
+    Pair,
+    /// Hits come from the matcher.
+    Contains,
+}
+
+/// Rule with its leaves replaced by probe indices.
+#[derive(Debug, Clone)]
+enum Node {
+    Probe(usize),
+    Not(Box<Node>),
+    And(Vec<Node>),
+    Or(Vec<Node>),
+}
+
+impl Engine {
+    /// Checks lines in bulk with a worker per CPU.
+    pub fn new(rule: &Rule) -> Self {
+        let mut probes = Vec::new();
+        let mut patterns = Vec::new();
+        let tree = Self::compile(rule, &mut probes, &mut patterns);
+        Self {
+            probes,
+            tree,
+            matcher: Matcher::new(&patterns),
+            threads: std::thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        }
+    }
+
+    /// Number of workers for bulk checks, at least one.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    fn compile<'r>(
+        rule: &'r Rule,
+        probes: &mut Vec<Probe>,
+        patterns: &mut Vec<(&'r [u8], usize)>,
+    ) -> Node {
+        let mut probe = |probe| {
+            probes.push(probe);
+            Node::Probe(probes.len() - 1)
+        };
+        match rule {
+            Rule::Count { letters, cmp, n } => {
+                let mut table = Box::new([false; 128]);
+                let mut others = Vec::new();
+                for letter in letters.chars() {
+                    match letter.is_ascii() {
+                        true => table[letter as usize] = true,
+                        false => others.push(letter),
+                    }
+                }
+                probe(Probe::Count {
+                    letters: table,
+                    others,
+                    cmp: *cmp,
+                    n: *n,
+                })
+            }
+            Rule::GapRepeat(gap) => probe(Probe::Repeat(*gap)),
+            Rule::PairRepeat => probe(Probe::Pair),
+            Rule::Contains(substrings) => {
+                let node = probe(Probe::Contains);
+                if let Node::Probe(index) = node {
+                    patterns.extend(substrings.iter().map(|s| (s.as_bytes(), index)));
+                }
+                node
+            }
+            Rule::Not(rule) => Node::Not(Box::new(Self::compile(rule, probes, patterns))),
+            Rule::And(rules) => Node::And(
+                rules
+                    .iter()
+                    .map(|rule| Self::compile(rule, probes, patterns))
+                    .collect(),
+            ),
+            Rule::Or(rules) => Node::Or(
+                rules
+                    .iter()
+                    .map(|rule| Self::compile(rule, probes, patterns))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Scanner to check many strings with, reusing its buffers.
+    pub fn scanner(&self) -> Scanner<'_> {
+        let pairs = self.probes.iter().any(|probe| matches!(probe, Probe::Pair));
+        Scanner {
+            engine: self,
+            hits: vec![0; self.probes.len()],
+            pair_stamps: if pairs { vec![0; 1 << 16] } else { vec![] },
+            pair_first: if pairs { vec![0; 1 << 16] } else { vec![] },
+            other_pairs: HashMap::new(),
+            chars: Vec::new(),
+            stamp: 0,
+        }
+    }
+
+    /// Verdict on every line of `input`, in order.
+    /// Lines end with `\n` or `\r\n`, and are split between workers.
+    pub fn check_lines(&self, input: &[u8]) -> Vec<bool> {
+        let chunks = split_lines(input, self.threads);
+        let check = |range: Range<usize>| {
+            let mut scanner = self.scanner();
+            lines(&input[range])
+                .map(|line| scanner.is_nice(line))
+                .collect::<Vec<_>>()
+        };
+        if chunks.len() == 1 {
+            return check(chunks[0].clone());
+        }
+        std::thread::scope(|s| {
+            let workers = chunks
+                .into_iter()
+                .map(|range| s.spawn(move || check(range)))
+                .collect::<Vec<_>>();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("worker doesn't panic"))
+                .collect()
+        })
+    }
+
+    pub fn count_nice_lines(&self, input: &[u8]) -> usize {
+        self.check_lines(input)
+            .into_iter()
+            .filter(|&nice| nice)
+            .count()
+    }
+}
+
+impl Checker for Engine {
+    fn is_nice<S: AsRef<str>>(&self, string: S) -> bool {
+        self.scanner().is_nice(string.as_ref().as_bytes())
+    }
+}
+
+/// Buffers of one worker, see [`Engine::scanner`].
+pub struct Scanner<'e> {
+    engine: &'e Engine,
+    /// Count of letters, or whether the probe hit.
+    hits: Vec<usize>,
+    /// Pairs seen in the current string are stamped with `stamp`,
+    /// so the tables never have to be cleared between strings.
+    pair_stamps: Vec<u32>,
+    pair_first: Vec<u32>,
+    /// First index of pairs with a char outside ASCII, in the current string.
+    other_pairs: HashMap<(char, char), usize>,
+    /// Chars of the current string, if it's outside ASCII.
+    chars: Vec<char>,
+    stamp: u32,
+}
+
+impl Scanner<'_> {
+    pub fn is_nice(&mut self, string: &[u8]) -> bool {
+        self.scan(string);
+        self.eval(&self.engine.tree)
+    }
+
+    fn scan(&mut self, string: &[u8]) {
+        let matcher = &self.engine.matcher;
+        self.hits.fill(0);
+        self.stamp = self.stamp.wrapping_add(1);
+        if self.stamp == 0 {
+            self.pair_stamps.fill(0);
+            self.stamp = 1;
+        }
+
+        // Empty patterns match before any byte.
+        for &probe in matcher.outputs(Matcher::ROOT) {
+            self.hits[probe] = 1;
+        }
+        let mut state = Matcher::ROOT;
+        for &byte in string {
+            state = matcher.next(state, byte);
+            for &probe in matcher.outputs(state) {
+                self.hits[probe] = 1;
+            }
+        }
+
+        if string.is_ascii() {
+            self.probe(string);
+        } else {
+            let mut chars = std::mem::take(&mut self.chars);
+            chars.clear();
+            chars.extend(String::from_utf8_lossy(string).chars());
+            self.other_pairs.clear();
+            self.probe(&chars);
+            self.chars = chars;
+        }
+    }
+
+    /// Updates probes but `contains` ones with every byte or char of a string.
+    fn probe<U: Copy + Eq + Into<char>>(&mut self, string: &[U]) {
+        for (i, &unit) in string.iter().enumerate() {
+            let c = unit.into();
+            for (probe, hits) in self.engine.probes.iter().zip(&mut self.hits) {
+                match probe {
+                    Probe::Count {
+                        letters, others, ..
+                    } => {
+                        *hits += match c.is_ascii() {
+                            true => letters[c as usize],
+                            false => others.contains(&c),
+                        } as usize
+                    }
+                    Probe::Repeat(gap) => {
+                        if i > *gap && string[i - gap - 1] == unit {
+                            *hits = 1;
+                        }
+                    }
+                    Probe::Pair if i > 0 => {
+                        let (prev, start) = (string[i - 1].into(), i - 1);
+                        let first = if prev.is_ascii() && c.is_ascii() {
+                            let pair = (prev as usize) << 8 | c as usize;
+                            if self.pair_stamps[pair] != self.stamp {
+                                self.pair_stamps[pair] = self.stamp;
+                                self.pair_first[pair] = start as u32;
+                            }
+                            self.pair_first[pair] as usize
+                        } else {
+                            *self.other_pairs.entry((prev, c)).or_insert(start)
+                        };
+                        if start >= first + 2 {
+                            *hits = 1;
+                        }
+                    }
+                    Probe::Pair | Probe::Contains => {}
+                }
+            }
+        }
+    }
+
+    fn eval(&self, node: &Node) -> bool {
+        match node {
+            Node::Probe(probe) => match &self.engine.probes[*probe] {
+                Probe::Count { cmp, n, .. } => cmp.holds(self.hits[*probe], *n),
+                _ => self.hits[*probe] > 0,
+            },
+            Node::Not(node) => !self.eval(node),
+            Node::And(nodes) => nodes.iter().all(|node| self.eval(node)),
+            Node::Or(nodes) => nodes.iter().any(|node| self.eval(node)),
+        }
+    }
+}
+
+/// Aho-Corasick automaton with a full transition table, so each byte is one lookup.
+#[derive(Debug, Clone)]
+struct Matcher {
+    /// `next[state * 256 + byte]`
+    next: Vec<u32>,
+    /// Probes of the patterns ending in each state, including through failure links.
+    outputs: Vec<Vec<usize>>,
+}
+
+impl Matcher {
+    const ROOT: u32 = 0;
+    const NONE: u32 = u32::MAX;
+
+    fn new(patterns: &[(&[u8], usize)]) -> Self {
+        let mut matcher = Self {
+            next: vec![Self::NONE; 256],
+            outputs: vec![vec![]],
+        };
+        for &(pattern, probe) in patterns {
+            let mut state = Self::ROOT;
+            for &byte in pattern {
+                let i = Self::index(state, byte);
+                if matcher.next[i] == Self::NONE {
+                    matcher.next[i] = matcher.outputs.len() as u32;
+                    matcher.next.extend([Self::NONE; 256]);
+                    matcher.outputs.push(vec![]);
+                }
+                state = matcher.next[i];
+            }
+            matcher.outputs[state as usize].push(probe);
+        }
+
+        // Breadth first, so failure states are complete before their use.
+        let mut fail = vec![Self::ROOT; matcher.outputs.len()];
+        let mut queue = std::collections::VecDeque::new();
+        for byte in 0..=255 {
+            match matcher.next[byte as usize] {
+                Self::NONE => matcher.next[byte as usize] = Self::ROOT,
+                state => queue.push_back(state),
+            }
+        }
+        while let Some(state) = queue.pop_front() {
+            for byte in 0..=255 {
+                let i = Self::index(state, byte);
+                let fallback = matcher.next[Self::index(fail[state as usize], byte)];
+                match matcher.next[i] {
+                    Self::NONE => matcher.next[i] = fallback,
+                    child => {
+                        fail[child as usize] = fallback;
+                        let inherited = matcher.outputs[fallback as usize].clone();
+                        matcher.outputs[child as usize].extend(inherited);
+                        queue.push_back(child);
+                    }
+                }
+            }
+        }
+        matcher
+    }
+
+    fn index(state: u32, byte: u8) -> usize {
+        state as usize * 256 + byte as usize
+    }
+
+    fn next(&self, state: u32, byte: u8) -> u32 {
+        self.next[Self::index(state, byte)]
+    }
+
+    fn outputs(&self, state: u32) -> &[usize] {
+        &self.outputs[state as usize]
+    }
+}
+
+/// Lines of `input`, without line endings, like [`str::lines`].
+fn lines(input: &[u8]) -> impl Iterator<Item = &[u8]> {
+    input
+        .strip_suffix(b"\n")
+        .unwrap_or(input)
+        .split(|&byte| byte == b'\n')
+        .filter(move |_| !input.is_empty())
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+}
+
+/// Splits `input` into at most `parts` ranges of whole lines.
+fn split_lines(input: &[u8], parts: usize) -> Vec<Range<usize>> {
+    let size = input.len().div_ceil(parts).max(1);
+    let mut ranges = Vec::with_capacity(parts);
+    let mut start = 0;
+    while start < input.len() {
+        let end = match input[(start + size).min(input.len())..]
+            .iter()
+            .position(|&byte| byte == b'\n')
+        {
+            Some(newline) => start + size + newline + 1,
+            None => input.len(),
+        };
+        ranges.push(start..end);
+        start = end;
+    }
+    if ranges.is_empty() {
+        ranges.push(0..0);
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{PART1, PART2};
+
+    fn engine(rule: &str) -> Engine {
+        Engine::new(&rule.parse().unwrap())
+    }
+
+    #[test]
+    fn same_as_rules() {
+        let strings = [
+            "ugknbfddgicrmopn",
+            "aaa",
+            "aaaa",
+            "jchzalrnumimnmhp",
+            "haegwjzuvuyypxyu",
+            "qjhvhtzxzqqjkmpb",
+            "xxyxx",
+            "uurcxstgmygtbstg",
+            "",
+            "éé",
+            "éaé",
+            "éxaéx",
+            "ééé",
+            "aéioué",
+        ];
+        for r in [
+            PART1,
+            PART2,
+            "!double | count(xyz)!=2",
+            "!(pair & repeat(2)) & contains(a,aa,bcd,cd)",
+            "contains(hers,his,she,he) & vowels<4",
+            "contains(aé) | count(xa)>1",
+        ] {
+            let (rule, engine) = (r.parse::<Rule>().unwrap(), engine(r));
+            let mut scanner = engine.scanner();
+            for s in strings {
+                assert_eq!(scanner.is_nice(s.as_bytes()), rule.holds(s), "{r} on {s}");
+            }
+        }
+    }
+
+    #[test]
+    fn letters_outside_ascii() {
+        let rule = Rule::count("éa", Cmp::Ge, 2);
+        let engine = Engine::new(&rule);
+        for s in ["é", "éa", "aa", "éé", "ab"] {
+            assert_eq!(engine.is_nice(s), rule.holds(s), "{s}");
+        }
+    }
+
+    #[test]
+    fn matcher() {
+        let matcher = Matcher::new(&[(b"he", 0), (b"she", 1), (b"his", 2), (b"hers", 3)]);
+        let mut found = Vec::new();
+        let mut state = Matcher::ROOT;
+        for &byte in b"ushers" {
+            state = matcher.next(state, byte);
+            found.extend_from_slice(matcher.outputs(state));
+        }
+        found.sort();
+        assert_eq!(found, [0, 1, 3]);
+    }
+
+    #[test]
+    fn check_lines() {
+        let input = b"ugknbfddgicrmopn\r\naaa\njchzalrnumimnmhp\n\nhaegwjzuvuyypxyu\n";
+        let expected = [true, true, false, false, false];
+        for threads in [1, 2, 3, 8] {
+            let engine = engine(PART1).with_threads(threads);
+            assert_eq!(engine.check_lines(input), expected, "{threads} threads");
+            assert_eq!(engine.count_nice_lines(input), 2);
+        }
+        assert_eq!(engine(PART1).check_lines(b""), []);
+        assert_eq!(engine(PART1).check_lines(b"aaa"), [true]);
+    }
+
+    #[test]
+    fn split_lines_whole() {
+        let input = b"ab\ncd\nef\ngh";
+        for parts in 1..6 {
+            let ranges = split_lines(input, parts);
+            assert!(ranges.len() <= parts);
+            assert_eq!(ranges.first().unwrap().start, 0);
+            assert_eq!(ranges.last().unwrap().end, input.len());
+            for pair in ranges.windows(2) {
+                assert_eq!(pair[0].end, pair[1].start);
+                assert_eq!(input[pair[0].end - 1], b'\n');
+            }
+        }
+    }
+}
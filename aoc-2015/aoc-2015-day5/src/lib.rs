@@ -0,0 +1,265 @@
+use helpers::{AocError, Solution};
+
+use engine::Engine;
+use nice::{Check, Checker};
+use rules::{PART1, PART2};
+
+pub mod engine;
+pub mod rules;
+pub mod verdict;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 5;
+
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, AocError> {
+        count_nice_lines(input, PART1)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, AocError> {
+        count_nice_lines(input, PART2)
+    }
+}
+
+fn count_nice_lines(input: &str, rule: &str) -> Result<usize, AocError> {
+    let rule = rule.parse().map_err(AocError::compute)?;
+    Ok(Engine::new(&rule).count_nice_lines(input.as_bytes()))
+}
+
+pub fn count_nice<S: AsRef<str>>(
+    lines: impl IntoIterator<Item = S>,
+    checker: &impl Checker,
+) -> usize {
+    lines
+        .into_iter()
+        .filter(|line| line.is_nice(checker))
+        .count()
+}
+
+pub mod nice {
+    use std::collections::HashSet;
+
+    pub trait Checker {
+        fn is_nice<S: AsRef<str>>(&self, string: S) -> bool;
+    }
+
+    pub trait Check {
+        fn is_nice(&self, checker: &impl Checker) -> bool;
+    }
+
+    impl<T: AsRef<str>> Check for T {
+        fn is_nice(&self, checker: &impl Checker) -> bool {
+            checker.is_nice(self)
+        }
+    }
+
+    pub struct CheckerPart1 {
+        want_vowels: usize,
+        want_double_letter: bool,
+        exclude_strings: Vec<String>,
+    }
+
+    impl Default for CheckerPart1 {
+        fn default() -> Self {
+            Self::new(3, true, vec!["ab", "cd", "pq", "xy"])
+        }
+    }
+
+    impl Checker for CheckerPart1 {
+        fn is_nice<S: AsRef<str>>(&self, string: S) -> bool {
+            let string = string.as_ref();
+            if Self::has_vowels(string) < self.want_vowels {
+                return false;
+            }
+            if self.want_double_letter ^ Self::has_double_letter(string) {
+                return false;
+            }
+            if self.has_excluded_strings(string) {
+                return false;
+            }
+            true
+        }
+    }
+
+    impl CheckerPart1 {
+        fn new<S: Into<String>>(
+            want_vowels: usize,
+            want_double_letter: bool,
+            excluded_strings: Vec<S>,
+        ) -> Self {
+            Self {
+                want_vowels,
+                want_double_letter,
+                exclude_strings: excluded_strings.into_iter().map(|s| s.into()).collect(),
+            }
+        }
+
+        fn has_double_letter(string: &str) -> bool {
+            let string: Vec<char> = string.chars().collect();
+            string.windows(2).any(|w| w[0] == w[1])
+        }
+
+        fn has_vowels(string: &str) -> usize {
+            const VOWELS: &str = "aeiou";
+            string
+                .chars()
+                .fold(0, |acc, c| if VOWELS.contains(c) { acc + 1 } else { acc })
+        }
+
+        fn has_excluded_strings(&self, string: &str) -> bool {
+            for excluded_string in &self.exclude_strings {
+                if string.contains(excluded_string) {
+                    return true;
+                }
+            }
+            false
+        }
+    }
+
+    pub struct CheckerPart2 {}
+
+    impl Default for CheckerPart2 {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl Checker for CheckerPart2 {
+        fn is_nice<S: AsRef<str>>(&self, string: S) -> bool {
+            let string = string.as_ref();
+            self.has_any_non_overlapped_duplex(string) && self.has_any_symmetrical_triplex(string)
+        }
+    }
+
+    impl CheckerPart2 {
+        fn new() -> Self {
+            Self {}
+        }
+
+        fn has_any_non_overlapped_duplex(&self, string: &str) -> bool {
+            let chars: Vec<_> = string.chars().collect();
+            let duplexes: Vec<_> = chars.windows(2).collect();
+            if HashSet::<_>::from_iter(duplexes.iter()).len() == duplexes.len() {
+                return false;
+            }
+
+            for i in 0..(duplexes.len() - 1) {
+                let (c1, c2) = (duplexes[i], duplexes[i + 1]);
+                if c1 == c2 {
+                    return false;
+                }
+            }
+            true
+        }
+
+        fn has_any_symmetrical_triplex(&self, string: &str) -> bool {
+            let chars: Vec<_> = string.chars().collect();
+            chars.windows(3).any(|w| w[0] == w[2])
+        }
+    }
+
+    #[cfg(test)]
+    mod tests_checker_part1 {
+        use super::*;
+
+        fn checker() -> CheckerPart1 {
+            CheckerPart1::default()
+        }
+
+        #[test]
+        fn test_is_nice1() {
+            assert!("ugknbfddgicrmopn".is_nice(&checker()));
+        }
+
+        #[test]
+        fn test_is_nice2() {
+            assert!("aaa".is_nice(&checker()));
+        }
+
+        #[test]
+        fn test_is_nice3() {
+            assert!(!"jchzalrnumimnmhp".is_nice(&checker()));
+        }
+
+        #[test]
+        fn test_is_nice4() {
+            assert!(!"haegwjzuvuyypxyu".is_nice(&checker()));
+        }
+
+        #[test]
+        fn test_is_nice5() {
+            assert!(!"dvszwmarrgswjxmb".is_nice(&checker()));
+        }
+    }
+
+    #[cfg(test)]
+    mod tests_checker_part2 {
+        use super::*;
+
+        fn checker() -> CheckerPart2 {
+            CheckerPart2::default()
+        }
+
+        #[test]
+        fn test_is_nice1() {
+            assert!("qjhvhtzxzqqjkmpb".is_nice(&checker()));
+        }
+
+        #[test]
+        fn test_is_nice2() {
+            assert!("xxyxx".is_nice(&checker()));
+        }
+
+        #[test]
+        fn test_is_nice3() {
+            assert!(!"uurcxstgmygtbstg".is_nice(&checker()));
+        }
+
+        #[test]
+        fn test_is_nice4() {
+            assert!(!"ieodomkazucvgmuy".is_nice(&checker()));
+        }
+
+        #[test]
+        fn test_is_nice5() {
+            assert!("xyxy".is_nice(&checker()));
+        }
+
+        #[test]
+        fn test_is_nice6() {
+            assert!(!"aabcdefgaa".is_nice(&checker()));
+        }
+
+        #[test]
+        fn test_is_nice7() {
+            assert!(!"aaa".is_nice(&checker()));
+        }
+
+        #[test]
+        fn test_is_nice8() {
+            assert!(!"xyx".is_nice(&checker()));
+        }
+
+        #[test]
+        fn test_is_nice9() {
+            assert!(!"abcdefeghi".is_nice(&checker()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+
+    helpers::example_tests!(Day5; nice_part1, nice_part2);
+}
@@ -1,240 +1,55 @@
-use std::io::BufRead;
-
-use nice::{Check, Checker};
-
-use crate::nice::{CheckerPart1, CheckerPart2};
-
-fn main() -> Result<(), std::io::Error> {
-    let path = std::env::args().skip(1).next().unwrap();
-    let file = std::fs::File::open(path).unwrap();
-    let reader = std::io::BufReader::new(file);
-    let lines = reader
-        .lines()
-        .collect::<Result<Vec<String>, std::io::Error>>()?;
+use std::path::PathBuf;
+
+use aoc_2015_day5::{
+    engine::Engine,
+    rules::{Rule, PART1, PART2},
+    Day5,
+};
+use helpers::{input::InputSource, Solution};
+
+/// Usage: `aoc-2015-day5 [--explain] [input] [rule]`
+///
+/// With `--explain`, prints the verdict of every rule on every line,
+/// for the part rules or the given one.
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let explain = args.iter().any(|arg| arg == "--explain");
+    args.retain(|arg| arg != "--explain");
+    let mut args = args.into_iter();
+
+    let path = args.next().map(PathBuf::from);
+    let input = InputSource::new(path.as_deref(), Day5::YEAR, Day5::DAY).read()?;
+    // Optional rule, e.g. "vowels>=3 & !contains(ab)"
+    let rule = args.next().map(|rule| rule.parse::<Rule>()).transpose()?;
+
+    if explain {
+        let rules = match rule {
+            Some(rule) => vec![rule],
+            None => vec![PART1.parse()?, PART2.parse()?],
+        };
+        for (i, line) in input.lines().enumerate() {
+            println!("line {}: {line}", i + 1);
+            for rule in &rules {
+                let verdict = rule.check(line);
+                let nice = if verdict.nice { "nice" } else { "naughty" };
+                print!("{nice}, {verdict}");
+            }
+        }
+        return Ok(());
+    }
 
+    let count_nice = |rule: &Rule| Engine::new(rule).count_nice_lines(input.as_bytes());
     println!(
         "Number of nice strings for part1: {}",
-        run(&lines, &CheckerPart1::default())
+        count_nice(&PART1.parse()?)
     );
     println!(
         "Number of nice strings for part2: {}",
-        run(&lines, &CheckerPart2::default())
+        count_nice(&PART2.parse()?)
     );
-
-    Ok(())
-}
-
-fn run(lines: &Vec<String>, checker: &impl Checker) -> usize {
-    lines.iter().filter(|line| line.is_nice(checker)).count()
-}
-
-mod nice {
-    use std::collections::HashSet;
-
-    pub trait Checker {
-        fn is_nice<S: AsRef<str>>(&self, string: S) -> bool;
-    }
-
-    pub trait Check {
-        fn is_nice(&self, checker: &impl Checker) -> bool;
-    }
-
-    impl<T: AsRef<str>> Check for T {
-        fn is_nice(&self, checker: &impl Checker) -> bool {
-            checker.is_nice(self)
-        }
-    }
-
-    pub struct CheckerPart1 {
-        want_vowels: usize,
-        want_double_letter: bool,
-        exclude_strings: Vec<String>,
-    }
-
-    impl Default for CheckerPart1 {
-        fn default() -> Self {
-            Self::new(3, true, vec!["ab", "cd", "pq", "xy"])
-        }
-    }
-
-    impl Checker for CheckerPart1 {
-        fn is_nice<S: AsRef<str>>(&self, string: S) -> bool {
-            let string = string.as_ref();
-            if Self::has_vowels(string) < self.want_vowels {
-                return false;
-            }
-            if self.want_double_letter ^ Self::has_double_letter(string) {
-                return false;
-            }
-            if self.has_excluded_strings(string) {
-                return false;
-            }
-            true
-        }
-    }
-
-    impl CheckerPart1 {
-        fn new<S: Into<String>>(
-            want_vowels: usize,
-            want_double_letter: bool,
-            excluded_strings: Vec<S>,
-        ) -> Self {
-            Self {
-                want_vowels,
-                want_double_letter,
-                exclude_strings: excluded_strings.into_iter().map(|s| s.into()).collect(),
-            }
-        }
-
-        fn has_double_letter(string: &str) -> bool {
-            let string: Vec<char> = string.chars().collect();
-            string.windows(2).any(|w| w[0] == w[1])
-        }
-
-        fn has_vowels(string: &str) -> usize {
-            const VOWELS: &str = "aeiou";
-            string
-                .chars()
-                .fold(0, |acc, c| if VOWELS.contains(c) { acc + 1 } else { acc })
-        }
-
-        fn has_excluded_strings(&self, string: &str) -> bool {
-            for excluded_string in &self.exclude_strings {
-                if string.contains(excluded_string) {
-                    return true;
-                }
-            }
-            false
-        }
-    }
-
-    pub struct CheckerPart2 {}
-
-    impl Default for CheckerPart2 {
-        fn default() -> Self {
-            Self::new()
-        }
-    }
-
-    impl Checker for CheckerPart2 {
-        fn is_nice<S: AsRef<str>>(&self, string: S) -> bool {
-            let string = string.as_ref();
-            self.has_any_non_overlapped_duplex(string) && self.has_any_symmetrical_triplex(string)
-        }
+    if let Some(rule) = rule {
+        println!("Number of nice strings for '{rule}': {}", count_nice(&rule));
     }
 
-    impl CheckerPart2 {
-        fn new() -> Self {
-            Self {}
-        }
-
-        fn has_any_non_overlapped_duplex(&self, string: &str) -> bool {
-            let chars: Vec<_> = string.chars().collect();
-            let duplexes: Vec<_> = chars.windows(2).collect();
-            if HashSet::<_>::from_iter(duplexes.iter()).len() == duplexes.len() {
-                return false;
-            }
-
-            for i in 0..(duplexes.len() - 1) {
-                let (c1, c2) = (duplexes[i], duplexes[i + 1]);
-                if c1 == c2 {
-                    return false;
-                }
-            }
-            true
-        }
-
-        fn has_any_symmetrical_triplex(&self, string: &str) -> bool {
-            let chars: Vec<_> = string.chars().collect();
-            chars.windows(3).any(|w| w[0] == w[2])
-        }
-    }
-
-    #[cfg(test)]
-    mod tests_checker_part1 {
-        use super::*;
-
-        fn checker() -> CheckerPart1 {
-            CheckerPart1::default()
-        }
-
-        #[test]
-        fn test_is_nice1() {
-            assert_eq!("ugknbfddgicrmopn".is_nice(&checker()), true);
-        }
-
-        #[test]
-        fn test_is_nice2() {
-            assert_eq!("aaa".is_nice(&checker()), true);
-        }
-
-        #[test]
-        fn test_is_nice3() {
-            assert_eq!("jchzalrnumimnmhp".is_nice(&checker()), false);
-        }
-
-        #[test]
-        fn test_is_nice4() {
-            assert_eq!("haegwjzuvuyypxyu".is_nice(&checker()), false);
-        }
-
-        #[test]
-        fn test_is_nice5() {
-            assert_eq!("dvszwmarrgswjxmb".is_nice(&checker()), false);
-        }
-    }
-
-    #[cfg(test)]
-    mod tests_checker_part2 {
-        use super::*;
-
-        fn checker() -> CheckerPart2 {
-            CheckerPart2::default()
-        }
-
-        #[test]
-        fn test_is_nice1() {
-            assert_eq!("qjhvhtzxzqqjkmpb".is_nice(&checker()), true);
-        }
-
-        #[test]
-        fn test_is_nice2() {
-            assert_eq!("xxyxx".is_nice(&checker()), true);
-        }
-
-        #[test]
-        fn test_is_nice3() {
-            assert_eq!("uurcxstgmygtbstg".is_nice(&checker()), false);
-        }
-
-        #[test]
-        fn test_is_nice4() {
-            assert_eq!("ieodomkazucvgmuy".is_nice(&checker()), false);
-        }
-
-        #[test]
-        fn test_is_nice5() {
-            assert_eq!("xyxy".is_nice(&checker()), true);
-        }
-
-        #[test]
-        fn test_is_nice6() {
-            assert_eq!("aabcdefgaa".is_nice(&checker()), false);
-        }
-
-        #[test]
-        fn test_is_nice7() {
-            assert_eq!("aaa".is_nice(&checker()), false);
-        }
-
-        #[test]
-        fn test_is_nice8() {
-            assert_eq!("xyx".is_nice(&checker()), false);
-        }
-
-        #[test]
-        fn test_is_nice9() {
-            assert_eq!("abcdefeghi".is_nice(&checker()), false);
-        }
-    }
+    Ok(())
 }
@@ -0,0 +1,516 @@
+//! Rules for nice strings, written in a small language:
+//! ```text
+//! vowels>=3 & double & !contains(ab,cd,pq,xy)
+//! ```
+//!
+//! | Rule                  | String is nice, if it                                  |
+//! |-----------------------|--------------------------------------------------------|
+//! | `vowels <cmp> N`      | has `aeiou` letters `<cmp>` N times                    |
+//! | `count(abc) <cmp> N`  | has any of `abc` ASCII letters `<cmp>` N times         |
+//! | `double`              | has a letter twice in a row, same as `repeat(0)`       |
+//! | `repeat(N)`           | has a letter repeated with N letters between           |
+//! | `pair`                | has a pair of letters twice, without overlapping       |
+//! | `contains(ab,cd)`     | contains any of the substrings                         |
+//! | `!r`, `a & b`, `a \| b` | doesn't follow `r`, follows both, follows any        |
+//!
+//! `<cmp>` is one of `>=`, `<=`, `>`, `<`, `=`, `==`, `!=`.
+//! `!` binds tighter than `&`, `&` binds tighter than `|`, parentheses group.
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    nice::Checker,
+    verdict::{count_letters, find_pair, find_repeat, find_substring},
+};
+
+pub const VOWELS: &str = "aeiou";
+
+/// Rules of part 1.
+pub const PART1: &str = "vowels>=3 & double & !contains(ab,cd,pq,xy)";
+/// Rules of part 2.
+pub const PART2: &str = "pair & repeat(1)";
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("unexpected {found} at {offset}, expected {expected}")]
+pub struct RuleError {
+    pub offset: usize,
+    pub found: String,
+    pub expected: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cmp {
+    Lt,
+    Le,
+    Eq,
+    Ne,
+    Ge,
+    Gt,
+}
+
+impl Cmp {
+    pub fn holds(self, left: usize, right: usize) -> bool {
+        match self {
+            Cmp::Lt => left < right,
+            Cmp::Le => left <= right,
+            Cmp::Eq => left == right,
+            Cmp::Ne => left != right,
+            Cmp::Ge => left >= right,
+            Cmp::Gt => left > right,
+        }
+    }
+}
+
+impl Display for Cmp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let cmp = match self {
+            Cmp::Lt => "<",
+            Cmp::Le => "<=",
+            Cmp::Eq => "=",
+            Cmp::Ne => "!=",
+            Cmp::Ge => ">=",
+            Cmp::Gt => ">",
+        };
+        f.write_str(cmp)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rule {
+    /// Number of letters from the set compared with `n`.
+    Count {
+        letters: String,
+        cmp: Cmp,
+        n: usize,
+    },
+    /// Letter repeated with `gap` letters between.
+    GapRepeat(usize),
+    /// Pair of letters appearing twice without overlapping.
+    PairRepeat,
+    /// Any of the substrings.
+    Contains(Vec<String>),
+    Not(Box<Rule>),
+    And(Vec<Rule>),
+    Or(Vec<Rule>),
+}
+
+impl Rule {
+    pub fn count(letters: impl Into<String>, cmp: Cmp, n: usize) -> Self {
+        Rule::Count {
+            letters: letters.into(),
+            cmp,
+            n,
+        }
+    }
+
+    pub fn contains<S: Into<String>>(substrings: impl IntoIterator<Item = S>) -> Self {
+        Rule::Contains(substrings.into_iter().map(Into::into).collect())
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        Rule::Not(Box::new(self))
+    }
+
+    pub fn and(self, other: Rule) -> Self {
+        match self {
+            Rule::And(mut rules) => {
+                rules.push(other);
+                Rule::And(rules)
+            }
+            rule => Rule::And(vec![rule, other]),
+        }
+    }
+
+    pub fn or(self, other: Rule) -> Self {
+        match self {
+            Rule::Or(mut rules) => {
+                rules.push(other);
+                Rule::Or(rules)
+            }
+            rule => Rule::Or(vec![rule, other]),
+        }
+    }
+
+    pub fn holds(&self, string: &str) -> bool {
+        match self {
+            Rule::Count { letters, cmp, n } => cmp.holds(count_letters(string, letters), *n),
+            Rule::GapRepeat(gap) => find_repeat(string, *gap).is_some(),
+            Rule::PairRepeat => find_pair(string).is_some(),
+            Rule::Contains(substrings) => find_substring(string, substrings).is_some(),
+            Rule::Not(rule) => !rule.holds(string),
+            Rule::And(rules) => rules.iter().all(|rule| rule.holds(string)),
+            Rule::Or(rules) => rules.iter().any(|rule| rule.holds(string)),
+        }
+    }
+
+    /// Binding strength, to put parentheses only where they are needed.
+    fn precedence(&self) -> u8 {
+        match self {
+            Rule::Or(_) => 0,
+            Rule::And(_) => 1,
+            _ => 2,
+        }
+    }
+
+    fn fmt_inner(&self, f: &mut std::fmt::Formatter<'_>, outer: u8) -> std::fmt::Result {
+        if self.precedence() < outer {
+            write!(f, "({self})")
+        } else {
+            write!(f, "{self}")
+        }
+    }
+}
+
+impl Display for Rule {
+    /// Formats the rule in the rule language, so it parses back to itself.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |f: &mut std::fmt::Formatter<'_>, rules: &[Rule], op| {
+            for (i, rule) in rules.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " {op} ")?;
+                }
+                // Nested rules of the same kind are grouped, to keep their shape.
+                rule.fmt_inner(f, self.precedence() + 1)?;
+            }
+            Ok(())
+        };
+        match self {
+            Rule::Count { letters, cmp, n } if letters == VOWELS => write!(f, "vowels{cmp}{n}"),
+            Rule::Count { letters, cmp, n } => write!(f, "count({letters}){cmp}{n}"),
+            Rule::GapRepeat(0) => f.write_str("double"),
+            Rule::GapRepeat(gap) => write!(f, "repeat({gap})"),
+            Rule::PairRepeat => f.write_str("pair"),
+            Rule::Contains(substrings) => write!(f, "contains({})", substrings.join(",")),
+            Rule::Not(rule) => {
+                f.write_str("!")?;
+                rule.fmt_inner(f, 2)
+            }
+            Rule::And(rules) => join(f, rules, "&"),
+            Rule::Or(rules) => join(f, rules, "|"),
+        }
+    }
+}
+
+impl Checker for Rule {
+    fn is_nice<S: AsRef<str>>(&self, string: S) -> bool {
+        self.holds(string.as_ref())
+    }
+}
+
+impl FromStr for Rule {
+    type Err = RuleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { s, offset: 0 };
+        let rule = parser.or()?;
+        parser.skip_whitespace();
+        if parser.offset < s.len() {
+            return Err(parser.unexpected("`&`, `|` or end of rule"));
+        }
+        Ok(rule)
+    }
+}
+
+struct Parser<'a> {
+    s: &'a str,
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.s[self.offset..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.offset += rest.len() - rest.trim_start().len();
+    }
+
+    /// Takes `token`, if the rest starts with it.
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        let found = self.rest().starts_with(token);
+        if found {
+            self.offset += token.len();
+        }
+        found
+    }
+
+    fn expect(&mut self, token: &'static str) -> Result<(), RuleError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.unexpected(token))
+        }
+    }
+
+    fn unexpected(&self, expected: &'static str) -> RuleError {
+        let found = match self.rest().chars().next() {
+            Some(c) => format!("'{c}'"),
+            None => "end of rule".to_owned(),
+        };
+        RuleError {
+            offset: self.offset,
+            found,
+            expected,
+        }
+    }
+
+    /// Takes the longest prefix of the rest, which chars satisfy `f`.
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.offset += len;
+        &rest[..len]
+    }
+
+    fn number(&mut self) -> Result<usize, RuleError> {
+        self.skip_whitespace();
+        let offset = self.offset;
+        self.take_while(|c| c.is_ascii_digit())
+            .parse()
+            .map_err(|_| RuleError {
+                offset,
+                ..self.unexpected("number")
+            })
+    }
+
+    fn or(&mut self) -> Result<Rule, RuleError> {
+        let mut rule = self.and()?;
+        while self.eat("|") {
+            rule = rule.or(self.and()?);
+        }
+        Ok(rule)
+    }
+
+    fn and(&mut self) -> Result<Rule, RuleError> {
+        let mut rule = self.not()?;
+        while self.eat("&") {
+            rule = rule.and(self.not()?);
+        }
+        Ok(rule)
+    }
+
+    fn not(&mut self) -> Result<Rule, RuleError> {
+        if self.eat("!") {
+            return Ok(self.not()?.not());
+        }
+        if self.eat("(") {
+            let rule = self.or()?;
+            self.expect(")")?;
+            return Ok(rule);
+        }
+        self.atom()
+    }
+
+    fn atom(&mut self) -> Result<Rule, RuleError> {
+        let offset = self.offset;
+        match self.take_while(|c| c.is_ascii_alphabetic()) {
+            "vowels" => Ok(Rule::count(VOWELS, self.cmp()?, self.number()?)),
+            "count" => {
+                self.expect("(")?;
+                let letters = self.take_while(|c| c != ')');
+                let start = self.offset - letters.len();
+                if let Some((i, c)) = letters.char_indices().find(|(_, c)| !c.is_ascii()) {
+                    return Err(RuleError {
+                        offset: start + i,
+                        found: format!("'{c}'"),
+                        expected: "ASCII letter",
+                    });
+                }
+                let letters = letters.trim().to_owned();
+                self.expect(")")?;
+                Ok(Rule::count(letters, self.cmp()?, self.number()?))
+            }
+            "double" => Ok(Rule::GapRepeat(0)),
+            "repeat" => {
+                self.expect("(")?;
+                let gap = self.number()?;
+                self.expect(")")?;
+                Ok(Rule::GapRepeat(gap))
+            }
+            "pair" => Ok(Rule::PairRepeat),
+            "contains" => {
+                self.expect("(")?;
+                let list = self.take_while(|c| c != ')');
+                self.expect(")")?;
+                Ok(Rule::contains(
+                    list.split(',')
+                        .map(str::trim)
+                        .filter(|substring| !substring.is_empty()),
+                ))
+            }
+            _ => {
+                self.offset = offset;
+                self.skip_whitespace();
+                Err(self.unexpected("rule"))
+            }
+        }
+    }
+
+    fn cmp(&mut self) -> Result<Cmp, RuleError> {
+        // Longer tokens go first, so `>=` is not taken for `>`.
+        const CMPS: [(&str, Cmp); 7] = [
+            (">=", Cmp::Ge),
+            ("<=", Cmp::Le),
+            ("==", Cmp::Eq),
+            ("!=", Cmp::Ne),
+            (">", Cmp::Gt),
+            ("<", Cmp::Lt),
+            ("=", Cmp::Eq),
+        ];
+        CMPS.into_iter()
+            .find(|(token, _)| self.eat(token))
+            .map(|(_, cmp)| cmp)
+            .ok_or_else(|| self.unexpected("comparison"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(s: &str) -> Rule {
+        s.parse().unwrap()
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn part1() {
+            assert_eq!(
+                rule(PART1),
+                Rule::count(VOWELS, Cmp::Ge, 3)
+                    .and(Rule::GapRepeat(0))
+                    .and(Rule::contains(["ab", "cd", "pq", "xy"]).not())
+            );
+        }
+
+        #[test]
+        fn part2() {
+            assert_eq!(rule(PART2), Rule::PairRepeat.and(Rule::GapRepeat(1)));
+        }
+
+        #[test]
+        fn precedence() {
+            assert_eq!(
+                rule("double | !pair & count( xyz ) != 2"),
+                Rule::GapRepeat(0).or(Rule::PairRepeat.not().and(Rule::count("xyz", Cmp::Ne, 2)))
+            );
+            assert_eq!(
+                rule("!(double | pair)"),
+                Rule::GapRepeat(0).or(Rule::PairRepeat).not()
+            );
+        }
+
+        #[test]
+        fn display_round_trip() {
+            for s in [
+                PART1,
+                PART2,
+                "double | !pair & count(xyz)!=2",
+                "!(double | pair) & (vowels<1 | repeat(2))",
+            ] {
+                assert_eq!(rule(s).to_string(), s);
+                assert_eq!(rule(&rule(s).to_string()), rule(s));
+            }
+        }
+
+        #[test]
+        fn errors() {
+            let err = |s: &str| s.parse::<Rule>().unwrap_err();
+            assert_eq!(
+                err("double & triple"),
+                RuleError {
+                    offset: 9,
+                    found: "'t'".to_owned(),
+                    expected: "rule"
+                }
+            );
+            assert_eq!(err("vowels 3").expected, "comparison");
+            assert_eq!(err("repeat(x)").expected, "number");
+            assert_eq!(err("(double").found, "end of rule");
+            assert_eq!(
+                err("count(aé)>1"),
+                RuleError {
+                    offset: 7,
+                    found: "'é'".to_owned(),
+                    expected: "ASCII letter"
+                }
+            );
+            assert_eq!(
+                err("double pair").to_string(),
+                "unexpected 'p' at 7, expected `&`, `|` or end of rule"
+            );
+        }
+    }
+
+    mod holds {
+        use super::*;
+
+        #[test]
+        fn count() {
+            assert!(rule("vowels>=3").holds("aei"));
+            assert!(!rule("vowels>3").holds("aei"));
+            assert!(rule("count(xy)==2").holds("xaya"));
+        }
+
+        #[test]
+        fn gap_repeat() {
+            assert!(rule("double").holds("abba"));
+            assert!(!rule("double").holds("aba"));
+            assert!(rule("repeat(1)").holds("aba"));
+            assert!(rule("repeat(2)").holds("abca"));
+        }
+
+        #[test]
+        fn pair_repeat() {
+            assert!(rule("pair").holds("xyxy"));
+            assert!(rule("pair").holds("aabcdefgaa"));
+            assert!(rule("pair").holds("aaaa"));
+            assert!(!rule("pair").holds("aaa"));
+        }
+
+        #[test]
+        fn contains() {
+            assert!(rule("contains(ab, xy)").holds("zzxyzz"));
+            assert!(!rule("contains(ab,xy)").holds("axbycd"));
+        }
+
+        #[test]
+        fn non_ascii() {
+            assert!(rule("double").holds("éé"));
+            assert!(!rule("repeat(1)").holds("éé"));
+            assert!(rule("repeat(1)").holds("éaé"));
+            assert!(rule("pair").holds("éaxéa"));
+            assert!(!rule("pair").holds("ééé"));
+            assert!(rule("count(a)==1 & contains(aé)").holds("éaé"));
+        }
+
+        #[test]
+        fn same_as_checkers() {
+            use crate::nice::{CheckerPart1, CheckerPart2};
+
+            let (part1, part2) = (rule(PART1), rule(PART2));
+            for s in [
+                "ugknbfddgicrmopn",
+                "aaa",
+                "jchzalrnumimnmhp",
+                "haegwjzuvuyypxyu",
+            ] {
+                assert_eq!(part1.is_nice(s), CheckerPart1::default().is_nice(s), "{s}");
+            }
+            for s in [
+                "qjhvhtzxzqqjkmpb",
+                "xxyxx",
+                "uurcxstgmygtbstg",
+                "ieodomkazucvgmuy",
+            ] {
+                assert_eq!(part2.is_nice(s), CheckerPart2::default().is_nice(s), "{s}");
+            }
+        }
+    }
+}
@@ -0,0 +1,293 @@
+//! Why a string follows a rule or not, see [`Rule::check`].
+
+use std::{collections::HashMap, fmt::Display};
+
+use crate::rules::Rule;
+
+/// What a rule found in the string. Offsets are in bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Evidence {
+    /// Number of letters from the set.
+    Count(usize),
+    /// First letter repeated with the gap, at the offset of its first occurrence.
+    Repeat { letter: char, offset: usize },
+    /// First pair of letters seen twice without overlapping.
+    Pair {
+        pair: String,
+        first: usize,
+        second: usize,
+    },
+    /// Earliest substring found.
+    Substring { substring: String, offset: usize },
+}
+
+impl Display for Evidence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Evidence::Count(count) => write!(f, "{count} letters"),
+            Evidence::Repeat { letter, offset } => write!(f, "'{letter}' repeated at {offset}"),
+            Evidence::Pair {
+                pair,
+                first,
+                second,
+            } => write!(f, "\"{pair}\" at {first} and {second}"),
+            Evidence::Substring { substring, offset } => write!(f, "\"{substring}\" at {offset}"),
+        }
+    }
+}
+
+/// Verdict of a rule on a string, with verdicts of its inner rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verdict<'r> {
+    pub rule: &'r Rule,
+    pub nice: bool,
+    pub evidence: Option<Evidence>,
+    pub inner: Vec<Verdict<'r>>,
+}
+
+impl Verdict<'_> {
+    /// Innermost rules or negations, which failed the string.
+    pub fn failed(&self) -> Vec<&Verdict<'_>> {
+        match self.rule {
+            _ if self.nice => vec![],
+            Rule::And(_) | Rule::Or(_) => self.inner.iter().flat_map(Verdict::failed).collect(),
+            _ => vec![self],
+        }
+    }
+
+    fn fmt_indented(&self, f: &mut std::fmt::Formatter<'_>, depth: usize) -> std::fmt::Result {
+        let verdict = if self.nice { "pass" } else { "fail" };
+        write!(
+            f,
+            "{:indent$}{verdict} {}",
+            "",
+            self.rule,
+            indent = depth * 2
+        )?;
+        if let Some(evidence) = &self.evidence {
+            write!(f, ": {evidence}")?;
+        }
+        writeln!(f)?;
+        self.inner
+            .iter()
+            .try_for_each(|verdict| verdict.fmt_indented(f, depth + 1))
+    }
+}
+
+impl Display for Verdict<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+impl Rule {
+    pub fn check(&self, string: &str) -> Verdict<'_> {
+        let leaf = |nice, evidence| Verdict {
+            rule: self,
+            nice,
+            evidence,
+            inner: vec![],
+        };
+        match self {
+            Rule::Count { letters, cmp, n } => {
+                let count = count_letters(string, letters);
+                leaf(cmp.holds(count, *n), Some(Evidence::Count(count)))
+            }
+            Rule::GapRepeat(gap) => {
+                let evidence = find_repeat(string, *gap);
+                leaf(evidence.is_some(), evidence)
+            }
+            Rule::PairRepeat => {
+                let evidence = find_pair(string);
+                leaf(evidence.is_some(), evidence)
+            }
+            Rule::Contains(substrings) => {
+                let evidence = find_substring(string, substrings);
+                leaf(evidence.is_some(), evidence)
+            }
+            Rule::Not(rule) => {
+                let inner = rule.check(string);
+                Verdict {
+                    rule: self,
+                    nice: !inner.nice,
+                    evidence: None,
+                    inner: vec![inner],
+                }
+            }
+            Rule::And(rules) | Rule::Or(rules) => {
+                let inner = rules
+                    .iter()
+                    .map(|rule| rule.check(string))
+                    .collect::<Vec<_>>();
+                let nice = match self {
+                    Rule::And(_) => inner.iter().all(|verdict| verdict.nice),
+                    _ => inner.iter().any(|verdict| verdict.nice),
+                };
+                Verdict {
+                    rule: self,
+                    nice,
+                    evidence: None,
+                    inner,
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn count_letters(string: &str, letters: &str) -> usize {
+    string.chars().filter(|&c| letters.contains(c)).count()
+}
+
+pub(crate) fn find_repeat(string: &str, gap: usize) -> Option<Evidence> {
+    let chars = string.char_indices().collect::<Vec<_>>();
+    // No string is long enough for a gap this large.
+    let width = gap.checked_add(2)?;
+    chars
+        .windows(width)
+        .find(|w| w[0].1 == w[width - 1].1)
+        .map(|w| Evidence::Repeat {
+            letter: w[0].1,
+            offset: w[0].0,
+        })
+}
+
+pub(crate) fn find_pair(string: &str) -> Option<Evidence> {
+    // Pairs are told apart by chars, so they overlap by chars too.
+    let chars = string.char_indices().collect::<Vec<_>>();
+    let mut first_seen = HashMap::new();
+    chars.windows(2).enumerate().find_map(|(i, pair)| {
+        let (first, first_offset) = *first_seen
+            .entry((pair[0].1, pair[1].1))
+            .or_insert((i, pair[0].0));
+        (i >= first + 2).then(|| Evidence::Pair {
+            pair: String::from_iter([pair[0].1, pair[1].1]),
+            first: first_offset,
+            second: pair[0].0,
+        })
+    })
+}
+
+pub(crate) fn find_substring(string: &str, substrings: &[String]) -> Option<Evidence> {
+    substrings
+        .iter()
+        .filter_map(|substring| Some((string.find(substring.as_str())?, substring)))
+        .min_by_key(|&(offset, _)| offset)
+        .map(|(offset, substring)| Evidence::Substring {
+            substring: substring.clone(),
+            offset,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{PART1, PART2};
+
+    fn rule(s: &str) -> Rule {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn evidence() {
+        let check = |r: &str, s: &str| rule(r).check(s).evidence;
+        assert_eq!(check("vowels>=3", "xazeyi"), Some(Evidence::Count(3)));
+        assert_eq!(
+            check("double", "abccdd"),
+            Some(Evidence::Repeat {
+                letter: 'c',
+                offset: 2
+            })
+        );
+        assert_eq!(
+            check("pair", "xyaxyaa"),
+            Some(Evidence::Pair {
+                pair: "xy".to_owned(),
+                first: 0,
+                second: 3
+            })
+        );
+        assert_eq!(
+            check("contains(pq,cd)", "abcdpq"),
+            Some(Evidence::Substring {
+                substring: "cd".to_owned(),
+                offset: 2
+            })
+        );
+        assert_eq!(check("contains(pq)", "abc"), None);
+    }
+
+    #[test]
+    fn non_ascii_evidence() {
+        let check = |r: &str, s: &str| rule(r).check(s).evidence;
+        assert_eq!(
+            check("double", "aéé"),
+            Some(Evidence::Repeat {
+                letter: 'é',
+                offset: 1
+            })
+        );
+        assert_eq!(check("repeat(1)", "éé"), None);
+        assert_eq!(
+            check("pair", "éxaéx"),
+            Some(Evidence::Pair {
+                pair: "éx".to_owned(),
+                first: 0,
+                second: 4
+            })
+        );
+    }
+
+    #[test]
+    fn huge_gap() {
+        let r = rule(&format!("repeat({})", usize::MAX));
+        assert_eq!(r, Rule::GapRepeat(usize::MAX));
+        let verdict = r.check("aaaa");
+        assert!(!verdict.nice);
+        assert_eq!(verdict.evidence, None);
+        assert!(!r.holds("aaaa"));
+    }
+
+    #[test]
+    fn failed_rules() {
+        let part1 = rule(PART1);
+        let verdict = part1.check("haegwjzuvuyypxyu");
+        assert!(!verdict.nice);
+        let failed = verdict.failed();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].rule, &rule("!contains(ab,cd,pq,xy)"));
+        assert_eq!(
+            failed[0].inner[0].evidence,
+            Some(Evidence::Substring {
+                substring: "xy".to_owned(),
+                offset: 13
+            })
+        );
+        assert!(part1.check("ugknbfddgicrmopn").failed().is_empty());
+    }
+
+    #[test]
+    fn same_as_holds() {
+        for r in [PART1, PART2, "!double | vowels<2", "!(pair & repeat(2))"] {
+            let r = rule(r);
+            for s in [
+                "ugknbfddgicrmopn",
+                "aaa",
+                "qjhvhtzxzqqjkmpb",
+                "ieodomkazucvgmuy",
+            ] {
+                assert_eq!(r.check(s).nice, r.holds(s), "{r} on {s}");
+            }
+        }
+    }
+
+    #[test]
+    fn display() {
+        let part2 = rule(PART2);
+        assert_eq!(
+            part2.check("xxyxx").to_string(),
+            "pass pair & repeat(1)\n  \
+               pass pair: \"xx\" at 0 and 3\n  \
+               pass repeat(1): 'x' repeated at 1\n"
+        );
+    }
+}
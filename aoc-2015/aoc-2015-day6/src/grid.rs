@@ -0,0 +1,276 @@
+use crate::{sparse::SparseGrid, Command, GridError, Instruction, Point, GRID_SZ};
+
+/// Light, which changes by instructions.
+pub trait Cell: Clone {
+    fn apply_instruction(&mut self, instruction: &Instruction);
+}
+
+/// Light of part 1, on or off.
+impl Cell for bool {
+    fn apply_instruction(&mut self, instruction: &Instruction) {
+        match instruction {
+            Instruction::TurnOn => *self = true,
+            Instruction::TurnOff => *self = false,
+            Instruction::Toggle => *self = !*self,
+        }
+    }
+}
+
+/// Light of part 2, which brightness never goes below zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Brightness(pub usize);
+
+impl Cell for Brightness {
+    fn apply_instruction(&mut self, instruction: &Instruction) {
+        match instruction {
+            Instruction::TurnOn => self.0 += 1,
+            Instruction::TurnOff => self.0 = self.0.saturating_sub(1),
+            Instruction::Toggle => self.0 += 2,
+        }
+    }
+}
+
+/// Like [`Brightness`], but never above `MAX`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SaturatingBrightness<const MAX: usize>(pub usize);
+
+impl<const MAX: usize> Cell for SaturatingBrightness<MAX> {
+    fn apply_instruction(&mut self, instruction: &Instruction) {
+        let mut brightness = Brightness(self.0);
+        brightness.apply_instruction(instruction);
+        self.0 = brightness.0.min(MAX);
+    }
+}
+
+/// Grid of lights, stored sparse, see [`SparseGrid`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<C>(SparseGrid<C>);
+
+impl<C: Cell + Default> Default for Grid<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Cell + Default> Grid<C> {
+    /// Puzzle grid, with every light at its default.
+    pub fn new() -> Self {
+        Self::with_size(GRID_SZ, GRID_SZ)
+    }
+
+    pub fn with_size(width: usize, height: usize) -> Self {
+        Self::filled(width, height, C::default())
+    }
+}
+
+impl<C: Cell> Grid<C> {
+    pub fn filled(width: usize, height: usize, cell: C) -> Self {
+        Self(SparseGrid::new(width, height, cell))
+    }
+
+    pub fn width(&self) -> usize {
+        self.0.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.0.height()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&C> {
+        self.0.get(point)
+    }
+
+    pub fn apply_cmd(&mut self, cmd: &Command) -> Result<(), GridError> {
+        if !cmd.rect.is_valid_in(self.width(), self.height()) {
+            return Err(GridError::InvalidRect(cmd.rect));
+        }
+
+        let Command { rect, instruction } = cmd;
+        self.0
+            .update(rect, |cell| cell.apply_instruction(instruction));
+        Ok(())
+    }
+
+    /// Sum of `f` over every light, `None` if it doesn't fit in `usize`.
+    pub fn sum_by(&self, f: impl Fn(&C) -> usize) -> Option<usize> {
+        self.0.blocks().try_fold(0usize, |sum, (area, cell)| {
+            let block_sum = area.checked_mul(f(cell) as u128)?;
+            sum.checked_add(block_sum.try_into().ok()?)
+        })
+    }
+
+    /// Number of lights equal to `want`, `None` if it doesn't fit in `usize`.
+    pub fn count(&self, want: &C) -> Option<usize>
+    where
+        C: PartialEq,
+    {
+        self.sum_by(|cell| (cell == want) as usize)
+    }
+}
+
+impl Grid<Brightness> {
+    /// `None` if it doesn't fit in `usize`.
+    pub fn total_brightness(&self) -> Option<usize> {
+        self.sum_by(|cell| cell.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rectangle, FIRST, LAST};
+
+    fn whole_grid(instruction: Instruction) -> Command {
+        Command {
+            instruction,
+            rect: Rectangle {
+                start: Point { x: FIRST, y: FIRST },
+                end: Point { x: LAST, y: LAST },
+            },
+        }
+    }
+
+    mod on_off {
+        use super::*;
+
+        #[test]
+        fn test_new() {
+            let grid = Grid::<bool>::new();
+            assert_eq!(grid.count(&false), Some(GRID_SZ * GRID_SZ));
+            assert_eq!(grid.count(&true), Some(0));
+        }
+
+        #[test]
+        fn test_turn_on() {
+            let mut grid = Grid::<bool>::new();
+            grid.apply_cmd(&whole_grid(Instruction::TurnOn)).unwrap();
+            assert_eq!(grid.count(&false), Some(0));
+        }
+
+        #[test]
+        fn test_turn_off() {
+            let mut grid = Grid::<bool>::new();
+            grid.apply_cmd(&whole_grid(Instruction::TurnOn)).unwrap();
+            grid.apply_cmd(&whole_grid(Instruction::TurnOff)).unwrap();
+            assert_eq!(grid.count(&true), Some(0));
+        }
+
+        #[test]
+        fn test_toggle() {
+            let mut grid = Grid::<bool>::new();
+            let cmd = whole_grid(Instruction::Toggle);
+            grid.apply_cmd(&cmd).unwrap();
+            assert_eq!(grid.count(&false), Some(0));
+            grid.apply_cmd(&cmd).unwrap();
+            assert_eq!(grid.count(&true), Some(0));
+        }
+    }
+
+    mod brightness {
+        use super::*;
+
+        #[test]
+        fn test_new() {
+            assert_eq!(Grid::<Brightness>::new().total_brightness(), Some(0));
+        }
+
+        #[test]
+        fn test_turn_on() {
+            let mut grid = Grid::<Brightness>::new();
+            grid.apply_cmd(&whole_grid(Instruction::TurnOn)).unwrap();
+            assert_eq!(grid.total_brightness(), Some(GRID_SZ * GRID_SZ));
+        }
+
+        #[test]
+        fn test_turn_off() {
+            let mut grid = Grid::<Brightness>::new();
+            grid.apply_cmd(&whole_grid(Instruction::TurnOn)).unwrap();
+            grid.apply_cmd(&whole_grid(Instruction::TurnOff)).unwrap();
+            assert_eq!(grid.total_brightness(), Some(0));
+            grid.apply_cmd(&whole_grid(Instruction::TurnOff)).unwrap();
+            assert_eq!(grid.total_brightness(), Some(0));
+        }
+
+        #[test]
+        fn test_toggle() {
+            let mut grid = Grid::<Brightness>::new();
+            let cmd = whole_grid(Instruction::Toggle);
+            grid.apply_cmd(&cmd).unwrap();
+            assert_eq!(grid.total_brightness(), Some(GRID_SZ * GRID_SZ * 2));
+            grid.apply_cmd(&cmd).unwrap();
+            assert_eq!(grid.total_brightness(), Some(GRID_SZ * GRID_SZ * 4));
+        }
+
+        #[test]
+        fn test_with_size() {
+            let mut grid = Grid::<Brightness>::with_size(4_000_000, 3);
+            let cmd = "toggle 1000000,1 through 3999999,2"
+                .parse::<Command>()
+                .unwrap();
+            grid.apply_cmd(&cmd).unwrap();
+            assert_eq!(grid.total_brightness(), Some(3_000_000 * 2 * 2));
+            let outside = "toggle 0,0 through 0,3".parse::<Command>().unwrap();
+            assert!(grid.apply_cmd(&outside).is_err());
+        }
+
+        #[test]
+        fn test_overflow() {
+            let mut grid = Grid::<Brightness>::with_size(usize::MAX, 2);
+            let cmd = "turn on 0,0 through 0,1".parse::<Command>().unwrap();
+            grid.apply_cmd(&cmd).unwrap();
+            assert_eq!(grid.total_brightness(), Some(2));
+            assert_eq!(grid.count(&Brightness(0)), None);
+            let toggle_all = Command {
+                instruction: Instruction::Toggle,
+                rect: Rectangle {
+                    start: Point { x: 0, y: 0 },
+                    end: Point {
+                        x: usize::MAX - 1,
+                        y: 1,
+                    },
+                },
+            };
+            grid.apply_cmd(&toggle_all).unwrap();
+            assert_eq!(grid.total_brightness(), None);
+        }
+
+        #[test]
+        fn test_saturating() {
+            let mut grid = Grid::<SaturatingBrightness<3>>::new();
+            let cmd = whole_grid(Instruction::Toggle);
+            grid.apply_cmd(&cmd).unwrap();
+            grid.apply_cmd(&cmd).unwrap();
+            assert_eq!(grid.sum_by(|cell| cell.0), Some(GRID_SZ * GRID_SZ * 3));
+        }
+    }
+
+    /// Cell defined outside of the crate: on turns red up, off turns green up,
+    /// toggle turns blue up, each wrapping around.
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    struct Rgb(u8, u8, u8);
+
+    impl Cell for Rgb {
+        fn apply_instruction(&mut self, instruction: &Instruction) {
+            match instruction {
+                Instruction::TurnOn => self.0 = self.0.wrapping_add(1),
+                Instruction::TurnOff => self.1 = self.1.wrapping_add(1),
+                Instruction::Toggle => self.2 = self.2.wrapping_add(1),
+            }
+        }
+    }
+
+    #[test]
+    fn user_defined_cell() {
+        let mut grid = Grid::<Rgb>::with_size(10, 10);
+        for cmd in [
+            "turn on 0,0 through 4,4",
+            "turn off 2,2 through 9,9",
+            "toggle 4,0 through 4,9",
+        ] {
+            grid.apply_cmd(&cmd.parse().unwrap()).unwrap();
+        }
+        assert_eq!(grid.get(Point { x: 3, y: 3 }), Some(&Rgb(1, 1, 0)));
+        assert_eq!(grid.get(Point { x: 4, y: 4 }), Some(&Rgb(1, 1, 1)));
+        assert_eq!(grid.count(&Rgb(0, 0, 0)), Some(20));
+    }
+}
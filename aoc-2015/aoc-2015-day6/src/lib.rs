@@ -0,0 +1,419 @@
+use std::{num::ParseIntError, str::FromStr};
+
+use helpers::{parse_lines, AocError, Solution};
+
+pub use grid::{Brightness, Cell, Grid, SaturatingBrightness};
+
+pub mod grid;
+pub mod sparse;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 6;
+
+    type Input = Vec<Command>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(cmds: &Self::Input) -> Result<Self::Answer1, AocError> {
+        apply_all::<bool>(cmds)?
+            .count(&true)
+            .ok_or_else(|| AocError::compute("number of lit lights doesn't fit in usize"))
+    }
+
+    fn part2(cmds: &Self::Input) -> Result<Self::Answer2, AocError> {
+        apply_all::<Brightness>(cmds)?
+            .total_brightness()
+            .ok_or_else(|| AocError::compute("total brightness doesn't fit in usize"))
+    }
+}
+
+fn apply_all<C: Cell + Default>(cmds: &[Command]) -> Result<Grid<C>, AocError> {
+    let mut grid = Grid::new();
+    for cmd in cmds {
+        grid.apply_cmd(cmd).map_err(AocError::compute)?;
+    }
+    Ok(grid)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Command>, AocError> {
+    parse_lines(input, str::parse)
+}
+
+/// Size of the puzzle grid, used by [`Grid::new`] and
+/// [`Rectangle::is_valid`].
+pub const GRID_SZ: usize = 1000;
+
+#[allow(dead_code)]
+const FIRST: usize = 0;
+
+#[allow(dead_code)]
+const LAST: usize = GRID_SZ - 1;
+
+#[derive(Debug)]
+pub enum FromStrError {
+    ParseError,
+}
+
+impl std::fmt::Display for FromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FromStrError::ParseError => write!(f, "invalid command"),
+        }
+    }
+}
+
+impl std::error::Error for FromStrError {}
+
+impl From<ParseIntError> for FromStrError {
+    fn from(_: ParseIntError) -> Self {
+        FromStrError::ParseError
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Instruction {
+    TurnOn,
+    TurnOff,
+    Toggle,
+}
+
+impl FromStr for Instruction {
+    type Err = FromStrError;
+
+    /// '.*on', '.*off', 'toggle'
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.ends_with("on") {
+            Ok(Instruction::TurnOn)
+        } else if s.ends_with("off") {
+            Ok(Instruction::TurnOff)
+        } else if s == "toggle" {
+            Ok(Instruction::Toggle)
+        } else {
+            Err(FromStrError::ParseError)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub fn is_inside_grid(&self) -> bool {
+        self.is_inside(GRID_SZ, GRID_SZ)
+    }
+
+    pub fn is_inside(&self, width: usize, height: usize) -> bool {
+        self.x < width && self.y < height
+    }
+
+    /// Returns true if `of` is on the top right of `this`.
+    /// Can be at the same line.
+    pub fn is_top_right_of(&self, other: &Point) -> bool {
+        self.x >= other.x && self.y >= other.y && self != other
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rectangle {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Rectangle {
+    /// Returns true if the rectangle is inside the grid,
+    /// end is at top-rignt of start (can be at same line).
+    /// Can be zero-sized.
+    pub fn is_valid(&self) -> bool {
+        self.is_valid_in(GRID_SZ, GRID_SZ)
+    }
+
+    /// Like [`is_valid`](Self::is_valid), for a grid of `width` x `height`.
+    pub fn is_valid_in(&self, width: usize, height: usize) -> bool {
+        self.start.is_inside(width, height)
+            && self.end.is_inside(width, height)
+            && (self.end.is_top_right_of(&self.start) || self.start == self.end)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Command {
+    pub instruction: Instruction,
+    pub rect: Rectangle,
+}
+
+impl FromStr for Command {
+    type Err = FromStrError;
+
+    /// Example strings:
+    /// turn on 171,630 through 656,769
+    /// turn off 417,276 through 751,500
+    /// toggle 559,485 through 584,534
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let split = s
+            .split([' ', ','])
+            .enumerate()
+            .filter_map(|(i, s)| if i == 0 && s == "turn" { None } else { Some(s) })
+            .collect::<Vec<_>>();
+        if split.len() != 6 {
+            return Err(FromStrError::ParseError);
+        }
+
+        let (instruction, sx, sy, ex, ey) = (
+            split[0].parse::<Instruction>()?,
+            split[1].parse::<usize>()?,
+            split[2].parse::<usize>()?,
+            split[4].parse::<usize>()?,
+            split[5].parse::<usize>()?,
+        );
+        let rect = Rectangle {
+            start: Point { x: sx, y: sy },
+            end: Point { x: ex, y: ey },
+        };
+
+        Ok(Command { instruction, rect })
+    }
+}
+
+#[derive(Debug)]
+pub enum GridError {
+    InvalidRect(Rectangle),
+}
+
+impl std::fmt::Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::InvalidRect(rect) => write!(f, "invalid rect: {rect:?}"),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+#[cfg(test)]
+mod tests {
+
+    mod instruction {
+        mod from_str {
+            use crate::Instruction;
+
+            macro_rules! test {
+                ($s:expr, $wanted:expr) => {
+                    let instruction = $s.parse::<Instruction>();
+                    assert_eq!(instruction.ok(), $wanted);
+                };
+            }
+
+            #[test]
+            fn test_on() {
+                test!("on", Some(Instruction::TurnOn));
+                test!("turn_on", Some(Instruction::TurnOn));
+                test!("turn on", Some(Instruction::TurnOn));
+            }
+
+            #[test]
+            fn test_off() {
+                test!("off", Some(Instruction::TurnOff));
+                test!("turn_off", Some(Instruction::TurnOff));
+                test!("turn off", Some(Instruction::TurnOff));
+            }
+
+            #[test]
+            fn test_toggle() {
+                test!("toggle", Some(Instruction::Toggle));
+            }
+
+            #[test]
+            fn test_invalid() {
+                test!("please toggle", None);
+                test!("on please", None);
+                test!("off please", None);
+            }
+        }
+    }
+
+    mod rectangle {
+        mod is_valid {
+            use crate::{Point, Rectangle, GRID_SZ};
+
+            const LAST: usize = GRID_SZ - 1;
+
+            macro_rules! test {
+                (($sx:expr, $sy:expr), ($ex:expr, $ey:expr) => $wanted:expr) => {
+                    assert_eq!(
+                        Rectangle {
+                            start: Point { x: $sx, y: $sy },
+                            end: Point { x: $ex, y: $ey }
+                        }
+                        .is_valid(),
+                        $wanted
+                    );
+                };
+            }
+
+            #[test]
+            fn test_eq_1() {
+                test!((0, 0), (1, 1) => true);
+            }
+
+            #[test]
+            fn test_eq_2() {
+                test!((3, 3), (5, 5) => true);
+            }
+
+            #[test]
+            fn test_eq_3() {
+                test!((LAST-1, LAST-1), (LAST, LAST) => true);
+            }
+
+            #[test]
+            fn test_line_1() {
+                test!((3, 3), (4, 3) => true);
+            }
+
+            #[test]
+            fn test_line_2() {
+                test!((3, 3), (3, 4) => true);
+            }
+
+            #[test]
+            fn test_line_invalie_1() {
+                test!((3, 3), (3, 2) => false);
+            }
+
+            #[test]
+            fn test_line_invalid_2() {
+                test!((3, 3), (2, 3) => false);
+            }
+
+            #[test]
+            fn test_zero_1() {
+                test!((0, 0), (0, 0) => true);
+            }
+
+            #[test]
+            fn test_zero_2() {
+                test!((5, 5), (5, 5) => true);
+            }
+
+            #[test]
+            fn test_zero_3() {
+                test!((LAST, LAST), (LAST, LAST) => true);
+            }
+
+            #[test]
+            fn test_invalid_3() {
+                test!((5, 5), (4, 4) => false);
+            }
+
+            #[test]
+            fn test_invalid_4() {
+                test!((5, 5), (6, 4) => false);
+            }
+
+            #[test]
+            fn test_invalid_5() {
+                test!((5, 5), (4, 6) => false);
+            }
+
+            #[test]
+            fn test_invalid_7() {
+                test!((GRID_SZ, GRID_SZ), (GRID_SZ+1, GRID_SZ+1) => false);
+            }
+        }
+    }
+
+    mod command {
+
+        mod from_str {
+
+            use crate::{Command, Instruction, Point, Rectangle};
+
+            #[test]
+            fn test_on() {
+                assert_eq!(
+                    "turn on 171,630 through 656,769".parse::<Command>().ok(),
+                    Some(Command {
+                        instruction: Instruction::TurnOn,
+                        rect: Rectangle {
+                            start: Point { x: 171, y: 630 },
+                            end: Point { x: 656, y: 769 },
+                        }
+                    })
+                );
+            }
+
+            #[test]
+            fn test_off() {
+                assert_eq!(
+                    "turn off 417,276 through 751,500".parse::<Command>().ok(),
+                    Some(Command {
+                        instruction: Instruction::TurnOff,
+                        rect: Rectangle {
+                            start: Point { x: 417, y: 276 },
+                            end: Point { x: 751, y: 500 },
+                        }
+                    })
+                );
+            }
+
+            #[test]
+            fn test_toggle() {
+                assert_eq!(
+                    "toggle 559,485 through 584,534".parse::<Command>().ok(),
+                    Some(Command {
+                        instruction: Instruction::Toggle,
+                        rect: Rectangle {
+                            start: Point { x: 559, y: 485 },
+                            end: Point { x: 584, y: 534 },
+                        }
+                    })
+                );
+            }
+
+            #[test]
+            fn test_invalid() {
+                const TEST_CASES: &[&str] = &[
+                    "turn onn 171,630 through 656,769",
+                    "turn on 171a,630 through 656,769",
+                    "turn on 171,a630 through 656,769",
+                    "turn on 171,630 through a656,769",
+                    "turn on 171,630 through 656,a769",
+                    "turn on 171,630 656,769",
+                    "turn of 417,276 through 751,500",
+                    "turn off a417,276 through 751,500",
+                    "turn off 417,a276 through 751,500",
+                    "turn off 417,276 through a751,500",
+                    "turn off 417,276 through 751,a500",
+                    "turn off 417,276 751,500",
+                    "togle 559,485 through 584,534",
+                    "toggle a559,485 through 584,534",
+                    "toggle 559,a485 through 584,534",
+                    "toggle 559,485 through a584,534",
+                    "toggle 559,485 through 584,a534",
+                    "toggle 559,485 584,534",
+                ];
+
+                for case in TEST_CASES {
+                    assert!(case.parse::<Command>().is_err());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+
+    helpers::example_tests!(Day6; every_light, toggle);
+}
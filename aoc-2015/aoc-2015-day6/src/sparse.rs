@@ -0,0 +1,194 @@
+//! Grid compressed into blocks of cells, which always had the same commands applied.
+//!
+//! Block edges are the edges of applied rectangles, so a grid with `n` rectangles
+//! has at most `(2n + 1)²` blocks, whatever its size.
+
+use crate::{Point, Rectangle};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseGrid<T> {
+    width: usize,
+    height: usize,
+    /// First x of every column of blocks, in increasing order, starting with 0.
+    xs: Vec<usize>,
+    /// First y of every row of blocks, in increasing order, starting with 0.
+    ys: Vec<usize>,
+    /// `blocks[row][column]`
+    blocks: Vec<Vec<T>>,
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Grid of `width` x `height` cells, all set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            xs: vec![0],
+            ys: vec![0],
+            blocks: vec![vec![value]],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of blocks the grid is stored in.
+    pub fn block_count(&self) -> usize {
+        self.xs.len() * self.ys.len()
+    }
+
+    /// Applies `f` to every block in `rect`, which must be inside the grid.
+    pub fn update(&mut self, rect: &Rectangle, mut f: impl FnMut(&mut T)) {
+        let (x0, x1) = (self.split_x(rect.start.x), self.split_x(rect.end.x + 1));
+        let (y0, y1) = (self.split_y(rect.start.y), self.split_y(rect.end.y + 1));
+        for row in &mut self.blocks[y0..y1] {
+            row[x0..x1].iter_mut().for_each(&mut f);
+        }
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if point.x >= self.width || point.y >= self.height {
+            return None;
+        }
+        let column = self.xs.partition_point(|&x| x <= point.x) - 1;
+        let row = self.ys.partition_point(|&y| y <= point.y) - 1;
+        Some(&self.blocks[row][column])
+    }
+
+    /// Every block with its number of cells, which may not fit in `usize`.
+    pub fn blocks(&self) -> impl Iterator<Item = (u128, &T)> {
+        let widths = spans(&self.xs, self.width);
+        spans(&self.ys, self.height)
+            .zip(&self.blocks)
+            .flat_map(move |(height, row)| {
+                widths
+                    .clone()
+                    .zip(row)
+                    .map(move |(width, cell)| (width as u128 * height as u128, cell))
+            })
+    }
+
+    /// Index of the column starting at `x`, splitting the one containing it.
+    /// `x` at the right edge gives the number of columns.
+    fn split_x(&mut self, x: usize) -> usize {
+        if x >= self.width {
+            return self.xs.len();
+        }
+        match self.xs.binary_search(&x) {
+            Ok(column) => column,
+            Err(column) => {
+                self.xs.insert(column, x);
+                for row in &mut self.blocks {
+                    row.insert(column, row[column - 1].clone());
+                }
+                column
+            }
+        }
+    }
+
+    /// Like [`split_x`](Self::split_x), for rows.
+    fn split_y(&mut self, y: usize) -> usize {
+        if y >= self.height {
+            return self.ys.len();
+        }
+        match self.ys.binary_search(&y) {
+            Ok(row) => row,
+            Err(row) => {
+                self.ys.insert(row, y);
+                self.blocks.insert(row, self.blocks[row - 1].clone());
+                row
+            }
+        }
+    }
+}
+
+/// Lengths of spans starting at `starts` and ending at the next one, or `end`.
+fn spans(starts: &[usize], end: usize) -> impl Iterator<Item = usize> + Clone + '_ {
+    starts
+        .iter()
+        .zip(starts.iter().copied().skip(1).chain([end]))
+        .map(|(start, next)| next - start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect((sx, sy): (usize, usize), (ex, ey): (usize, usize)) -> Rectangle {
+        Rectangle {
+            start: Point { x: sx, y: sy },
+            end: Point { x: ex, y: ey },
+        }
+    }
+
+    fn sum(grid: &SparseGrid<usize>) -> u128 {
+        grid.blocks()
+            .map(|(area, &value)| area * value as u128)
+            .sum()
+    }
+
+    #[test]
+    fn update() {
+        let mut grid = SparseGrid::new(10, 5, 0);
+        grid.update(&rect((2, 1), (4, 3)), |v| *v += 1);
+        assert_eq!(sum(&grid), 9);
+        assert_eq!(grid.block_count(), 9);
+        assert_eq!(grid.get(Point { x: 2, y: 1 }), Some(&1));
+        assert_eq!(grid.get(Point { x: 5, y: 1 }), Some(&0));
+        assert_eq!(grid.get(Point { x: 10, y: 1 }), None);
+
+        grid.update(&rect((4, 3), (9, 4)), |v| *v += 10);
+        assert_eq!(sum(&grid), 9 + 12 * 10);
+        assert_eq!(grid.get(Point { x: 4, y: 3 }), Some(&11));
+        assert_eq!(grid.get(Point { x: 9, y: 4 }), Some(&10));
+    }
+
+    #[test]
+    fn whole_grid() {
+        let mut grid = SparseGrid::new(1_000_000, 1_000_000, 0);
+        grid.update(&rect((0, 0), (999_999, 999_999)), |v| *v += 1);
+        assert_eq!(grid.block_count(), 1);
+        assert_eq!(sum(&grid), 1_000_000 * 1_000_000);
+    }
+
+    #[test]
+    fn largest_grid() {
+        let mut grid = SparseGrid::new(usize::MAX, usize::MAX, 0);
+        grid.update(&rect((1, 1), (usize::MAX - 1, usize::MAX - 1)), |v| *v = 1);
+        let max = usize::MAX as u128;
+        assert_eq!(sum(&grid), (max - 1) * (max - 1));
+    }
+
+    #[test]
+    fn same_as_dense() {
+        let (width, height) = (17, 11);
+        let mut dense = vec![vec![0; width]; height];
+        let mut grid = SparseGrid::new(width, height, 0);
+        let rects = [
+            rect((0, 0), (5, 5)),
+            rect((3, 2), (16, 2)),
+            rect((7, 0), (7, 10)),
+            rect((4, 4), (12, 9)),
+            rect((16, 10), (16, 10)),
+        ];
+        for (i, r) in rects.iter().enumerate() {
+            grid.update(r, |v| *v = *v * 3 + i);
+            for row in &mut dense[r.start.y..=r.end.y] {
+                for v in &mut row[r.start.x..=r.end.x] {
+                    *v = *v * 3 + i;
+                }
+            }
+        }
+        for (y, row) in dense.iter().enumerate() {
+            for (x, v) in row.iter().enumerate() {
+                assert_eq!(grid.get(Point { x, y }), Some(v), "({x}, {y})");
+            }
+        }
+        assert_eq!(sum(&grid), dense.iter().flatten().sum::<usize>() as u128);
+    }
+}
@@ -13,6 +13,12 @@ pub enum ComputeError {
     GateNotFound(Gate),
 }
 
+impl Default for Circuit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Circuit {
     pub fn new() -> Self {
         Self {
@@ -1,3 +1,63 @@
+use circuit::{Circuit, ComputeError};
+use helpers::{parse_lines, AocError, Solution};
+use wiring::{GateOrNumber, Number, Op, Wire};
+
 pub mod circuit;
-pub mod error;
 pub mod wiring;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 7;
+
+    type Input = Circuit;
+    type Answer1 = u16;
+    type Answer2 = u16;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(circuit: &Self::Input) -> Result<Self::Answer1, AocError> {
+        part1(circuit.clone()).map_err(AocError::compute)
+    }
+
+    fn part2(circuit: &Self::Input) -> Result<Self::Answer2, AocError> {
+        let a_signal = part1(circuit.clone()).map_err(AocError::compute)?;
+        part2(circuit.clone(), a_signal).map_err(AocError::compute)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Circuit, AocError> {
+    let mut circuit = Circuit::new();
+    for wire in parse_lines(input, str::parse::<Wire>)? {
+        circuit.set(wire);
+    }
+    Ok(circuit)
+}
+
+/// what signal is ultimately provided to wire a?
+/// Param circuit should be circuit in initial state.
+pub fn part1(mut circuit: Circuit) -> Result<u16, ComputeError> {
+    circuit.get(&"a".into())
+}
+
+/// Now, take the signal you got on wire a, override wire b to that signal,
+/// and reset the other wires (including wire a).
+/// What new signal is ultimately provided to wire a?
+/// Param circuit should be circuit in initial state.
+pub fn part2(mut circuit: Circuit, a_signal: u16) -> Result<u16, ComputeError> {
+    circuit.set(Wire {
+        op: Op::GateOrNumber(GateOrNumber::Number(Number(a_signal))),
+        target: "b".into(),
+    });
+    circuit.get(&"a".into())
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+
+    helpers::example_tests!(Day7; website);
+}
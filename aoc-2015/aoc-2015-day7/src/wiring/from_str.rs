@@ -146,7 +146,7 @@ impl FromStr for UnaryOp {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let splitted = s.trim().split_whitespace().collect::<Vec<_>>();
+        let splitted = s.split_whitespace().collect::<Vec<_>>();
         if splitted.len() != 2 {
             return Err(ParseError {
                 kind: ParseErrorKind::InvalidLength,
@@ -163,7 +163,7 @@ impl FromStr for BinaryOp {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let splitted = s.trim().split_whitespace().collect::<Vec<_>>();
+        let splitted = s.split_whitespace().collect::<Vec<_>>();
         if splitted.len() != 3 {
             return Err(ParseError {
                 kind: ParseErrorKind::InvalidLength,
@@ -185,7 +185,7 @@ impl FromStr for ShiftOp {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let splitted = s.trim().split_whitespace().collect::<Vec<_>>();
+        let splitted = s.split_whitespace().collect::<Vec<_>>();
         if splitted.len() != 3 {
             return Err(ParseError {
                 kind: ParseErrorKind::InvalidLength,
@@ -1 +1,59 @@
+use helpers::{parse_lines, AocError, Solution};
+use line::Line;
+
 pub mod line;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 8;
+
+    type Input = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Self::Answer1, AocError> {
+        Ok(part1(lines))
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Self::Answer2, AocError> {
+        Ok(part2(lines))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Line>, AocError> {
+    parse_lines(input, |line| Line::new(line.to_owned()))
+}
+
+/// Returns total (code len, memory len) of all lines.
+pub fn count<'a>(lines: impl IntoIterator<Item = &'a Line>) -> (usize, usize) {
+    let (mut code_len, mut memory_len) = (0, 0);
+    for line in lines {
+        code_len += line.len_in_code();
+        memory_len += line.len_in_memory();
+    }
+    (code_len, memory_len)
+}
+
+pub fn part1(lines: &[Line]) -> usize {
+    let (code_len, memory_len) = count(lines);
+    code_len - memory_len
+}
+
+pub fn part2(lines: &[Line]) -> usize {
+    let lines = lines.iter().map(|line| line.encode()).collect::<Vec<_>>();
+    let (code_len, memory_len) = count(&lines);
+    code_len - memory_len
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+
+    helpers::example_tests!(Day8; strings);
+}
@@ -1,41 +1,21 @@
-use aoc_2015_day8::line::Line;
-use helpers::get_filepath_from_args;
+use aoc_2015_day8::{count, line::Line, parse, Day8};
 
-fn main() {
-    let filepath = get_filepath_from_args();
-    let input = std::fs::read_to_string(filepath).unwrap();
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let input = helpers::input::from_args::<Day8>()?;
 
-    let lines = input
-        .lines()
-        .map(|line| Line::new(line.to_owned()).unwrap())
-        .collect::<Vec<_>>();
+    let lines = parse(&input)?;
 
     println!("Part 1:");
-    run_part1(&lines);
+    print_count(&lines);
     println!("Part 2:");
-    run_part2(lines);
+    let lines = lines.iter().map(|line| line.encode()).collect::<Vec<_>>();
+    print_count(&lines);
+    Ok(())
 }
 
-fn count<'a>(lines: impl Iterator<Item = &'a Line>) {
-    let (mut code_len, mut memory_len) = (0, 0);
-    for line in lines {
-        code_len += line.len_in_code();
-        memory_len += line.len_in_memory();
-    }
+fn print_count(lines: &[Line]) {
+    let (code_len, memory_len) = count(lines);
     println!("code len: {}", code_len);
     println!("memory len: {}", memory_len);
     println!("difference: {}", code_len - memory_len);
 }
-
-fn run_part1(lines: &Vec<Line>) {
-    count(lines.iter());
-}
-
-fn run_part2(lines: Vec<Line>) {
-    let lines = lines
-        .into_iter()
-        .map(|line| line.encode())
-        .collect::<Vec<_>>();
-
-    count(lines.iter());
-}
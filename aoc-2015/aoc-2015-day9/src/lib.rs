@@ -1 +1,50 @@
+use helpers::{parse_lines, AocError, Solution};
+use my_graph::{Edge, Graph};
+
 pub mod my_graph;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 9;
+
+    type Input = Vec<Edge>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(edges: &Self::Input) -> Result<Self::Answer1, AocError> {
+        part1(edges).ok_or_else(|| AocError::compute("no path found"))
+    }
+
+    fn part2(edges: &Self::Input) -> Result<Self::Answer2, AocError> {
+        part2(edges).ok_or_else(|| AocError::compute("no path found"))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Edge>, AocError> {
+    parse_lines(input, str::parse)
+}
+
+/// Distance of the shortest route, which visits every location exactly once.
+pub fn part1(edges: &[Edge]) -> Option<u32> {
+    let paths = Graph::from_vec(edges).get_all_paths();
+    paths.shortest().map(|path| path.dist())
+}
+
+/// Distance of the longest route, which visits every location exactly once.
+pub fn part2(edges: &[Edge]) -> Option<u32> {
+    let paths = Graph::from_vec(edges).get_all_paths();
+    paths.longest().map(|path| path.dist())
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+
+    helpers::example_tests!(Day9; ireland);
+}
@@ -1,26 +1,21 @@
-use std::{collections::HashMap, io::BufRead, str::FromStr};
+use std::collections::HashMap;
 
-use aoc_2015_day9::my_graph;
+use aoc_2015_day9::{my_graph, parse, Day9};
 use petgraph::{algo, prelude::*};
 
-fn main() {
-    let filepath = helpers::get_filepath_from_args();
-    let file = std::fs::File::open(filepath).unwrap();
-    let reader = std::io::BufReader::new(file);
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let input = helpers::input::from_args::<Day9>()?;
 
-    let edges = reader
-        .lines()
-        .flat_map(|line| line.map(|line| my_graph::Edge::from_str(&line)))
-        .collect::<Result<Vec<_>, _>>()
-        .unwrap();
+    let edges = parse(&input)?;
 
     run(&edges, run_my, "my");
     run(&edges, run_petagraph, "pethagraph");
+    Ok(())
 }
 
 fn run(
-    edges: &Vec<my_graph::Edge>,
-    f: impl Fn(&Vec<my_graph::Edge>) -> (Option<u32>, Option<u32>),
+    edges: &[my_graph::Edge],
+    f: impl Fn(&[my_graph::Edge]) -> (Option<u32>, Option<u32>),
     description: &str,
 ) {
     let start = std::time::Instant::now();
@@ -31,7 +26,7 @@ fn run(
     );
 }
 
-fn run_my(edges: &Vec<my_graph::Edge>) -> (Option<u32>, Option<u32>) {
+fn run_my(edges: &[my_graph::Edge]) -> (Option<u32>, Option<u32>) {
     let graph = my_graph::Graph::from_vec(edges);
 
     let mut paths = graph.get_all_paths();
@@ -43,7 +38,7 @@ fn run_my(edges: &Vec<my_graph::Edge>) -> (Option<u32>, Option<u32>) {
     )
 }
 
-fn run_petagraph(edges: &Vec<my_graph::Edge>) -> (Option<u32>, Option<u32>) {
+fn run_petagraph(edges: &[my_graph::Edge]) -> (Option<u32>, Option<u32>) {
     let mut graph = petgraph::graph::UnGraph::<String, u32>::new_undirected();
 
     let mut edges_map: HashMap<&str, NodeIndex<u32>> = HashMap::new();
@@ -72,9 +67,7 @@ fn run_petagraph(edges: &Vec<my_graph::Edge>) -> (Option<u32>, Option<u32>) {
     for from_idx in 0..edges.len() {
         let from = edges[from_idx].1;
 
-        for to_idx in from_idx + 1..edges.len() {
-            let to = edges[to_idx].1;
-
+        for &(_, to) in &edges[from_idx + 1..] {
             let paths = algo::all_simple_paths::<Vec<_>, _>(
                 &graph,
                 from,
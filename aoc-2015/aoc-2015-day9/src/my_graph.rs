@@ -50,6 +50,12 @@ pub struct Graph<'a> {
     map: HashMap<&'a str, HashMap<&'a str, u32>>,
 }
 
+impl<'a> Default for Graph<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'a> Graph<'a> {
     pub fn new() -> Graph<'a> {
         Graph {
@@ -57,7 +63,7 @@ impl<'a> Graph<'a> {
         }
     }
 
-    pub fn from_vec(edges: &'a Vec<Edge>) -> Graph<'a> {
+    pub fn from_vec(edges: &'a [Edge]) -> Graph<'a> {
         Graph::from_iter(edges.iter())
     }
 
@@ -66,11 +72,8 @@ impl<'a> Graph<'a> {
         self.add_single_edge(to, from, *dist);
     }
 
-    fn add_single_edge(&mut self, from: &'a String, to: &'a String, dist: u32) {
-        self.map
-            .entry(from)
-            .or_insert(HashMap::new())
-            .insert(to, dist);
+    fn add_single_edge(&mut self, from: &'a str, to: &'a str, dist: u32) {
+        self.map.entry(from).or_default().insert(to, dist);
     }
 
     pub fn get_all_paths(&self) -> Paths {
@@ -82,19 +85,19 @@ impl<'a> Graph<'a> {
     }
 
     pub fn get_paths(&self, from: &str) -> Result<Paths, GraphError> {
-        self.get_paths_to_inner(HashSet::new(), &from.to_owned(), None)
+        self.get_paths_to_inner(HashSet::new(), from, None)
     }
 
     pub fn get_paths_to(&self, from: &str, dest: &str) -> Result<Paths, GraphError> {
         if !self.map.contains_key(dest) {
             return Err(GraphError::VertexNotFound(dest.to_owned()));
         }
-        self.get_paths_to_inner(HashSet::new(), &from.to_owned(), Some(dest))
+        self.get_paths_to_inner(HashSet::new(), from, Some(dest))
     }
 
     /// * `dest` -> Must be present in graph, othervise function will panic.
-    /// Caller must ensure of it before calling this function.
-    /// It's done to avoid checks for each iteration.
+    ///   Caller must ensure of it before calling this function.
+    ///   It's done to avoid checks for each iteration.
     fn get_paths_to_inner(
         &self,
         mut visited: HashSet<String>,
@@ -118,7 +121,7 @@ impl<'a> Graph<'a> {
             if !visited.contains(to) {
                 let paths = self.get_paths_to_inner(visited.clone(), to, dest)?;
 
-                if paths.0.len() == 0 {
+                if paths.0.is_empty() {
                     let mut path = head.clone();
                     path.push(PathItem::Vertex(to.to_owned()));
                     res.push(Path(path));
@@ -268,7 +271,7 @@ mod tests {
         #[test]
         fn get_all_paths_simple() {
             let edges: &[Edge] = &[E!("A", "B", 1)];
-            let graph = Graph::from_iter(edges.into_iter());
+            let graph = Graph::from_iter(edges);
 
             let a_paths = graph.get_paths("A").unwrap();
             assert_eq!(a_paths.len(), 1);
@@ -289,7 +292,7 @@ mod tests {
                 E!("London", "Belfast", 518),
                 E!("Dublin", "Belfast", 141),
             ];
-            let graph = Graph::from_iter(edges.into_iter());
+            let graph = Graph::from_iter(edges);
 
             let london_paths = graph.get_paths("London").unwrap();
             assert_eq!(london_paths.len(), 2);
@@ -308,7 +311,7 @@ mod tests {
                 E!("London", "Belfast", 518),
                 E!("Dublin", "Belfast", 141),
             ];
-            let graph = Graph::from_iter(edges.into_iter());
+            let graph = Graph::from_iter(edges);
 
             let mut paths: Paths = graph.get_paths_to("London", "Belfast").unwrap();
             assert_eq!(paths.len(), 2);
@@ -0,0 +1,50 @@
+//! Benchmarks of every day on its checked-in input.
+//! Days without input are skipped.
+//!
+//! Run with `cargo bench -p aoc`, or `cargo bench -p aoc -- 2015/day7` for a single day.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use helpers::{input::InputSource, Solution};
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let Ok(input) = InputSource::new(None, S::YEAR, S::DAY).read() else {
+        eprintln!("Skipping {} day {}: no input", S::YEAR, S::DAY);
+        return;
+    };
+    let parsed = S::parse(&input).expect("checked-in input should parse");
+
+    let mut group = c.benchmark_group(format!("{}/day{}", S::YEAR, S::DAY));
+    // Some days take seconds per run.
+    group.sample_size(10);
+    group.bench_function("parse", |b| b.iter(|| S::parse(&input)));
+    group.bench_function("part1", |b| b.iter(|| S::part1(&parsed)));
+    group.bench_function("part2", |b| b.iter(|| S::part2(&parsed)));
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<aoc_2015_day1::Day1>(c);
+    bench_day::<aoc_2015_day2::Day2>(c);
+    bench_day::<aoc_2015_day3::Day3>(c);
+    bench_day::<aoc_2015_day4::Day4>(c);
+    bench_day::<aoc_2015_day5::Day5>(c);
+    bench_day::<aoc_2015_day6::Day6>(c);
+    bench_day::<aoc_2015_day7::Day7>(c);
+    bench_day::<aoc_2015_day8::Day8>(c);
+    bench_day::<aoc_2015_day9::Day9>(c);
+    bench_day::<aoc_2015_day10::Day10>(c);
+    bench_day::<aoc_2015_day11::Day11>(c);
+    bench_day::<aoc_2015_day12::Day12>(c);
+    bench_day::<aoc_2015_day13::Day13>(c);
+    bench_day::<aoc_2015_day14::Day14>(c);
+    bench_day::<aoc_2015_day15::Day15>(c);
+    bench_day::<aoc_2015_day16::Day16>(c);
+    bench_day::<aoc_2015_day17::Day17>(c);
+    bench_day::<aoc_2015_day18::Day18>(c);
+    bench_day::<aoc_2015_day19::Day19>(c);
+    bench_day::<aoc_2015_day20::Day20>(c);
+    bench_day::<aoc_2015_day21::Day21>(c);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
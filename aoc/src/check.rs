@@ -0,0 +1,231 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use helpers::{input::InputError, Answers, AocError, ErrorKind};
+use serde::Deserialize;
+
+use crate::runner::Outcome;
+
+/// Known-good answers of a year, e.g. `aoc-2015/answers.toml`:
+/// ```toml
+/// [day7]
+/// part1 = "46065"
+/// part2 = "14134"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub struct KnownAnswers(BTreeMap<String, DayAnswers>);
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct DayAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AnswersError {
+    #[error("Answers file for {0} not found, tried {1:?}")]
+    NotFound(u16, Vec<PathBuf>),
+    #[error("Can't read answers {}: {1}", .0.display())]
+    Io(PathBuf, #[source] std::io::Error),
+    #[error("Can't parse answers {}: {1}", .0.display())]
+    Toml(PathBuf, #[source] toml::de::Error),
+}
+
+impl KnownAnswers {
+    /// Loads answers from `path` if given, or from the conventional location,
+    /// where they are optional.
+    pub fn load_or_default(path: Option<&Path>, year: u16) -> Result<Self, AnswersError> {
+        match path {
+            Some(path) => Self::load(path),
+            None => match Self::of_year(year) {
+                Err(AnswersError::NotFound(..)) => Ok(Self::default()),
+                res => res,
+            },
+        }
+    }
+
+    pub fn default_path(year: u16) -> PathBuf {
+        format!("aoc-{year}/answers.toml").into()
+    }
+
+    /// Loads answers of `year` from the conventional location.
+    pub fn of_year(year: u16) -> Result<Self, AnswersError> {
+        let tried = helpers::input::search_paths(&Self::default_path(year));
+        match tried.iter().find(|path| path.is_file()) {
+            Some(path) => Self::load(path),
+            None => Err(AnswersError::NotFound(year, tried)),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let contents =
+            std::fs::read_to_string(path).map_err(|e| AnswersError::Io(path.to_owned(), e))?;
+        toml::from_str(&contents).map_err(|e| AnswersError::Toml(path.to_owned(), e))
+    }
+
+    pub fn of_day(&self, day: u8) -> DayAnswers {
+        self.0
+            .get(&format!("day{day}"))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        got: String,
+    },
+    /// No known answer or no input to check against.
+    Missing,
+    Error(String),
+}
+
+impl Status {
+    fn of_part(expected: Option<&String>, got: &Result<String, AocError>) -> Self {
+        match (expected, got) {
+            (_, Err(e)) => Status::Error(e.to_string()),
+            (None, Ok(_)) => Status::Missing,
+            (Some(expected), Ok(got)) if expected == got => Status::Pass,
+            (Some(expected), Ok(got)) => Status::Fail {
+                expected: expected.clone(),
+                got: got.clone(),
+            },
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Fail { .. } | Status::Error(_))
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => f.pad("pass"),
+            Status::Fail { .. } => f.pad("FAIL"),
+            Status::Missing => f.pad("missing"),
+            Status::Error(_) => f.pad("ERROR"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckRow {
+    pub year: u16,
+    pub day: u8,
+    pub parts: [Status; 2],
+}
+
+impl CheckRow {
+    pub fn is_failure(&self) -> bool {
+        self.parts.iter().any(Status::is_failure)
+    }
+}
+
+/// Compares answers of `outcome` with the known ones.
+/// A day without input is reported as missing rather than failed.
+pub fn check(outcome: &Outcome, known: &DayAnswers) -> CheckRow {
+    let failed = |e: &AocError| [Status::Error(e.to_string()), Status::Error(e.to_string())];
+    let parts = match &outcome.answers {
+        Err(AocError {
+            kind: ErrorKind::Input(InputError::NotFound { .. }),
+            ..
+        }) => [Status::Missing, Status::Missing],
+        Err(e) => failed(e),
+        Ok(Answers { part1, part2, .. }) => [
+            Status::of_part(known.part1.as_ref(), part1),
+            Status::of_part(known.part2.as_ref(), part2),
+        ],
+    };
+    CheckRow {
+        year: outcome.puzzle.year,
+        day: outcome.puzzle.day,
+        parts,
+    }
+}
+
+/// Prints a pass/fail table followed by details of every failure.
+pub fn print_table(rows: &[CheckRow]) {
+    println!("{:<6} {:>3}  {:<8} part 2", "year", "day", "part 1");
+    for row in rows {
+        println!(
+            "{:<6} {:>3}  {:<8} {}",
+            row.year, row.day, row.parts[0], row.parts[1]
+        );
+    }
+    print_failures(rows);
+}
+
+/// Prints why each failed part has failed.
+pub fn print_failures(rows: &[CheckRow]) {
+    for row in rows {
+        for (i, status) in row.parts.iter().enumerate() {
+            match status {
+                Status::Fail { expected, got } => println!(
+                    "{} day {} part {}: expected {expected}, got {got}",
+                    row.year,
+                    row.day,
+                    i + 1
+                ),
+                Status::Error(e) => {
+                    println!("{} day {} part {}: {e}", row.year, row.day, i + 1)
+                }
+                Status::Pass | Status::Missing => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answers() {
+        let known: KnownAnswers = toml::from_str(
+            r#"
+            [day1]
+            part1 = "42"
+
+            [day2]
+            part1 = "1"
+            part2 = "abc"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(known.of_day(1).part1.as_deref(), Some("42"));
+        assert_eq!(known.of_day(1).part2, None);
+        assert_eq!(known.of_day(2).part2.as_deref(), Some("abc"));
+        assert_eq!(known.of_day(3).part1, None);
+    }
+
+    #[test]
+    fn status_of_part() {
+        let expected = "42".to_owned();
+        assert_eq!(
+            Status::of_part(Some(&expected), &Ok("42".to_owned())),
+            Status::Pass
+        );
+        assert_eq!(
+            Status::of_part(Some(&expected), &Ok("41".to_owned())),
+            Status::Fail {
+                expected: "42".to_owned(),
+                got: "41".to_owned()
+            }
+        );
+        assert_eq!(Status::of_part(None, &Ok("41".to_owned())), Status::Missing);
+        assert!(Status::of_part(None, &Err(AocError::compute("oops"))).is_failure());
+    }
+
+    #[test]
+    fn checked_in_answers_load() {
+        let known = KnownAnswers::of_year(2015).unwrap();
+        assert_eq!(known.of_day(7).part1.as_deref(), Some("46065"));
+    }
+}
@@ -0,0 +1,250 @@
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use check::{CheckRow, KnownAnswers};
+use clap::{Args, CommandFactory, Parser, Subcommand};
+#[cfg(feature = "http")]
+use helpers::input::{self, CachedFetcher, Fetcher, HttpFetcher};
+use helpers::{
+    input::{InputError, InputSource},
+    Answers, AocError, Puzzle,
+};
+use report::Format;
+
+mod check;
+mod registry;
+mod report;
+mod runner;
+mod scaffold;
+
+/// Runs Advent of Code solutions.
+///
+/// Examples: `aoc 2015 7`, `aoc 2015 all`, `aoc new 2016 1`.
+#[derive(Debug, Parser)]
+#[command(version, args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[command(flatten)]
+    run: Option<RunArgs>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Generates crate for a new day with a solution skeleton and an input placeholder.
+    New {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Workspace root.
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    /// Puzzle year.
+    year: u16,
+    /// Puzzle day, or `all` to run every registered day of the year.
+    day: DaySelector,
+    /// Puzzle input file, `-` for stdin.
+    /// Defaults to `aoc-<year>/input/aoc-<year>-day<day>.txt`.
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+    /// Download missing inputs from the Advent of Code site into `aoc-<year>/input/`.
+    /// Needs the session cookie in `AOC_SESSION` environment variable.
+    #[cfg(feature = "http")]
+    #[arg(long)]
+    fetch: bool,
+    /// Compare answers with the known ones and print pass/fail table.
+    #[arg(long)]
+    check: bool,
+    /// Known answers file, to check answers with.
+    /// Only used by `--check` and the summary of `all`, where mismatches are shown.
+    /// Defaults to `aoc-<year>/answers.toml`.
+    #[arg(long)]
+    answers: Option<PathBuf>,
+    /// Number of days to solve in parallel.
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: u16,
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = Format::Text, conflicts_with = "check")]
+    format: Format,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DaySelector {
+    All,
+    Day(u8),
+}
+
+impl FromStr for DaySelector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(DaySelector::All),
+            _ => match s.parse() {
+                Ok(day @ 1..=25) => Ok(DaySelector::Day(day)),
+                _ => Err(format!("expected day in 1..=25 or `all`, got '{s}'")),
+            },
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match (cli.command, cli.run) {
+        (Some(Command::New { year, day, root }), _) => new_day(&root, year, day),
+        (None, Some(args)) => run_days(&args),
+        (None, None) => {
+            Cli::command()
+                .print_help()
+                .expect("help should be printable");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new_day(root: &Path, year: u16, day: u8) -> ExitCode {
+    match scaffold::new_day(root, year, day) {
+        Ok(files) => {
+            for file in files {
+                println!("created {}", file.display());
+            }
+            println!(
+                "To make it runnable by `aoc`, add `aoc-{year}-day{day}` to aoc/Cargo.toml \
+                 and `Puzzle::of::<aoc_{year}_day{day}::Day{day}>()` to aoc/src/registry.rs"
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_days(cli: &RunArgs) -> ExitCode {
+    let puzzles = match cli.day {
+        DaySelector::All => registry::of_year(cli.year),
+        DaySelector::Day(day) => registry::find(cli.year, day).into_iter().collect(),
+    };
+    if cli.input.is_some() && cli.day == DaySelector::All {
+        eprintln!("Input file can't be used with `all`");
+        return ExitCode::FAILURE;
+    }
+    if puzzles.is_empty() {
+        eprintln!("No solutions found for {} day {:?}", cli.year, cli.day);
+        return ExitCode::FAILURE;
+    }
+
+    // Mismatches fail the run only where they are shown,
+    // elsewhere only errors do.
+    let compare = cli.check || (cli.format == Format::Text && cli.day == DaySelector::All);
+    let known = if compare {
+        match KnownAnswers::load_or_default(cli.answers.as_deref(), cli.year) {
+            Ok(known) => known,
+            Err(e) => {
+                eprintln!("{e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        KnownAnswers::default()
+    };
+
+    let start = Instant::now();
+    let outcomes = runner::solve_all(&puzzles, cli.jobs.into(), |puzzle| read_input(cli, puzzle));
+    let elapsed = start.elapsed();
+
+    let mut failed = false;
+    if compare {
+        let rows = outcomes
+            .iter()
+            .map(|outcome| check::check(outcome, &known.of_day(outcome.puzzle.day)))
+            .collect::<Vec<_>>();
+        failed = rows.iter().any(CheckRow::is_failure);
+        if cli.check {
+            check::print_table(&rows);
+        } else {
+            report::print_summary(&outcomes, &rows);
+            println!("{} days solved in {elapsed:.2?}", outcomes.len());
+        }
+    } else if cli.format == Format::Json {
+        for outcome in outcomes {
+            failed |= !print_json(&outcome.puzzle, outcome.answers);
+        }
+    } else {
+        for outcome in outcomes {
+            failed |= !print_text(&outcome.puzzle, outcome.answers);
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn read_input(cli: &RunArgs, puzzle: &Puzzle) -> Result<String, InputError> {
+    let source = InputSource::new(cli.input.as_deref(), puzzle.year, puzzle.day);
+    #[cfg(feature = "http")]
+    if cli.fetch {
+        let fetcher = CachedFetcher::new(input::workspace_root(), |year, day| {
+            HttpFetcher::from_env()?.fetch(year, day)
+        });
+        return source.read_or_fetch(&fetcher);
+    }
+    source.read()
+}
+
+/// Returns false if the day failed.
+fn print_text(puzzle: &Puzzle, answers: Result<Answers, AocError>) -> bool {
+    println!("{} day {}:", puzzle.year, puzzle.day);
+    match answers {
+        Ok(answers) => {
+            let timings = answers.timings;
+            println!("  parse: {:.2?}", timings.parse);
+            let part1_ok = print_part(1, answers.part1, timings.part1);
+            let part2_ok = print_part(2, answers.part2, timings.part2);
+            part1_ok && part2_ok
+        }
+        Err(e) => {
+            println!("  {e}");
+            false
+        }
+    }
+}
+
+/// Returns false if the day failed.
+fn print_json(puzzle: &Puzzle, answers: Result<Answers, AocError>) -> bool {
+    let records = report::records(puzzle, answers);
+    for record in &records {
+        println!(
+            "{}",
+            serde_json::to_string(record).expect("record is always serializable")
+        );
+    }
+    records.iter().all(|record| record.error.is_none())
+}
+
+/// Returns false if the part failed.
+fn print_part(part: u8, answer: Result<String, AocError>, elapsed: Duration) -> bool {
+    match answer {
+        Ok(answer) => {
+            println!("  part {part}: {answer} ({elapsed:.2?})");
+            true
+        }
+        Err(e) => {
+            println!("  part {part} failed after {elapsed:.2?}: {e}");
+            false
+        }
+    }
+}
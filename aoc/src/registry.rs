@@ -0,0 +1,65 @@
+use helpers::Puzzle;
+
+/// All solutions, known to the runner.
+/// New days should be added here to become runnable.
+pub fn puzzles() -> Vec<Puzzle> {
+    vec![
+        Puzzle::of::<aoc_2015_day1::Day1>(),
+        Puzzle::of::<aoc_2015_day2::Day2>(),
+        Puzzle::of::<aoc_2015_day3::Day3>(),
+        Puzzle::of::<aoc_2015_day4::Day4>(),
+        Puzzle::of::<aoc_2015_day5::Day5>(),
+        Puzzle::of::<aoc_2015_day6::Day6>(),
+        Puzzle::of::<aoc_2015_day7::Day7>(),
+        Puzzle::of::<aoc_2015_day8::Day8>(),
+        Puzzle::of::<aoc_2015_day9::Day9>(),
+        Puzzle::of::<aoc_2015_day10::Day10>(),
+        Puzzle::of::<aoc_2015_day11::Day11>(),
+        Puzzle::of::<aoc_2015_day12::Day12>(),
+        Puzzle::of::<aoc_2015_day13::Day13>(),
+        Puzzle::of::<aoc_2015_day14::Day14>(),
+        Puzzle::of::<aoc_2015_day15::Day15>(),
+        Puzzle::of::<aoc_2015_day16::Day16>(),
+        Puzzle::of::<aoc_2015_day17::Day17>(),
+        Puzzle::of::<aoc_2015_day18::Day18>(),
+        Puzzle::of::<aoc_2015_day19::Day19>(),
+        Puzzle::of::<aoc_2015_day20::Day20>(),
+        Puzzle::of::<aoc_2015_day21::Day21>(),
+    ]
+}
+
+pub fn find(year: u16, day: u8) -> Option<Puzzle> {
+    puzzles()
+        .into_iter()
+        .find(|p| p.year == year && p.day == day)
+}
+
+pub fn of_year(year: u16) -> Vec<Puzzle> {
+    puzzles().into_iter().filter(|p| p.year == year).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_unique() {
+        let puzzles = puzzles();
+        for (i, a) in puzzles.iter().enumerate() {
+            for b in &puzzles[i + 1..] {
+                assert!(
+                    (a.year, a.day) != (b.year, b.day),
+                    "{} day {}",
+                    a.year,
+                    a.day
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn find_works() {
+        assert_eq!(find(2015, 7).map(|p| p.day), Some(7));
+        assert!(find(2015, 26).is_none());
+    }
+}
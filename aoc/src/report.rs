@@ -0,0 +1,142 @@
+use std::time::Duration;
+
+use helpers::{Answers, AocError, Puzzle};
+use serde::Serialize;
+
+use crate::{
+    check::{self, CheckRow},
+    runner::Outcome,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Human readable text.
+    Text,
+    /// One JSON record per line for every part.
+    Json,
+}
+
+/// Result of a single part, as printed by `--format json`.
+/// `answer` is `null` if the part failed, `error` tells why.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub elapsed_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Record {
+    fn new(puzzle: &Puzzle, part: u8, answer: Result<String, AocError>, elapsed: Duration) -> Self {
+        let (answer, error) = match answer {
+            Ok(answer) => (Some(answer), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        Self {
+            year: puzzle.year,
+            day: puzzle.day,
+            part,
+            answer,
+            elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+            error,
+        }
+    }
+}
+
+/// Records of both parts.
+/// If the day failed as a whole, both parts share its error.
+pub fn records(puzzle: &Puzzle, answers: Result<Answers, AocError>) -> [Record; 2] {
+    match answers {
+        Ok(Answers {
+            part1,
+            part2,
+            timings,
+        }) => [
+            Record::new(puzzle, 1, part1, timings.part1),
+            Record::new(puzzle, 2, part2, timings.part2),
+        ],
+        Err(e) => {
+            let e = e.to_string();
+            [1, 2].map(|part| Record {
+                year: puzzle.year,
+                day: puzzle.day,
+                part,
+                answer: None,
+                elapsed_ms: 0.0,
+                error: Some(e.clone()),
+            })
+        }
+    }
+}
+
+/// Prints answers with their statuses and time of every day, followed by failures.
+pub fn print_summary(outcomes: &[Outcome], rows: &[CheckRow]) {
+    println!(
+        "{:<6} {:>3}  {:<20} {:<8} {:<20} {:<8} time",
+        "year", "day", "part 1", "", "part 2", ""
+    );
+    for (outcome, row) in outcomes.iter().zip(rows) {
+        let answers = outcome.answers.as_ref().ok();
+        let answer = |part: fn(&Answers) -> &Result<String, AocError>| {
+            answers
+                .and_then(|answers| part(answers).as_ref().ok())
+                .map_or("-", String::as_str)
+        };
+        let time = answers.map_or("-".to_owned(), |answers| {
+            format!("{:.2?}", answers.timings.total())
+        });
+        println!(
+            "{:<6} {:>3}  {:<20} {:<8} {:<20} {:<8} {time}",
+            row.year,
+            row.day,
+            answer(|answers| &answers.part1),
+            row.parts[0],
+            answer(|answers| &answers.part2),
+            row.parts[1],
+        );
+    }
+    check::print_failures(rows);
+}
+
+#[cfg(test)]
+mod tests {
+    use helpers::Timings;
+
+    use super::*;
+
+    fn puzzle() -> Puzzle {
+        Puzzle::of::<aoc_2015_day2::Day2>()
+    }
+
+    #[test]
+    fn json_record() {
+        let answers = Answers {
+            part1: Ok("42".to_owned()),
+            part2: Err(AocError::compute("oops")),
+            timings: Timings {
+                part1: Duration::from_micros(1500),
+                ..Timings::default()
+            },
+        };
+        let [part1, part2] = records(&puzzle(), Ok(answers));
+        assert_eq!(
+            serde_json::to_string(&part1).unwrap(),
+            r#"{"year":2015,"day":2,"part":1,"answer":"42","elapsed_ms":1.5}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&part2).unwrap(),
+            r#"{"year":2015,"day":2,"part":2,"answer":null,"elapsed_ms":0.0,"error":"Compute error: oops"}"#
+        );
+    }
+
+    #[test]
+    fn failed_day_fails_both_parts() {
+        let records = records(&puzzle(), Err(AocError::parse("bad input")));
+        assert!(records
+            .iter()
+            .all(|r| r.answer.is_none() && r.error.is_some()));
+    }
+}
@@ -0,0 +1,130 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::Mutex,
+};
+
+use helpers::{input::InputError, Answers, AocError, Puzzle};
+
+/// What a day has ended up with.
+#[derive(Debug)]
+pub struct Outcome {
+    pub puzzle: Puzzle,
+    pub answers: Result<Answers, AocError>,
+}
+
+/// Solves `puzzles` on `jobs` threads.
+/// Outcomes keep the order of `puzzles`.
+pub fn solve_all<R>(puzzles: &[Puzzle], jobs: usize, read_input: R) -> Vec<Outcome>
+where
+    R: Fn(&Puzzle) -> Result<String, InputError> + Sync,
+{
+    // A panicking day fails on its own, the rest still get solved.
+    let solve = |puzzle: &Puzzle| Outcome {
+        puzzle: *puzzle,
+        answers: panic::catch_unwind(AssertUnwindSafe(|| {
+            read_input(puzzle)
+                .map_err(AocError::from)
+                .and_then(|input| puzzle.solve(&input))
+        }))
+        .unwrap_or_else(|payload| {
+            Err(
+                AocError::compute(format!("panicked: {}", panic_message(&*payload)))
+                    .in_day(puzzle.year, puzzle.day),
+            )
+        }),
+    };
+
+    let queue = Mutex::new(puzzles.iter().enumerate());
+    let mut outcomes = std::thread::scope(|s| {
+        let workers = (0..jobs.clamp(1, puzzles.len().max(1)))
+            .map(|_| {
+                s.spawn(|| {
+                    let mut outcomes = Vec::new();
+                    loop {
+                        // Separate statement, so the lock isn't held while solving.
+                        let next = queue.lock().expect("queue isn't poisoned").next();
+                        let Some((i, puzzle)) = next else {
+                            break outcomes;
+                        };
+                        outcomes.push((i, solve(puzzle)));
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("panics are caught by `solve`"))
+            .collect::<Vec<_>>()
+    });
+    outcomes.sort_by_key(|(i, _)| *i);
+    outcomes.into_iter().map(|(_, outcome)| outcome).collect()
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => message,
+        (_, Some(message)) => message,
+        _ => "unknown cause",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_order() {
+        let puzzles = crate::registry::of_year(2015);
+        // Days fail without solving, so only the order is tested.
+        let outcomes = solve_all(&puzzles, 4, |puzzle| {
+            Err(InputError::NotFound {
+                year: puzzle.year,
+                day: puzzle.day,
+                tried: Vec::new(),
+            })
+        });
+        assert!(outcomes
+            .iter()
+            .map(|outcome| outcome.puzzle.day)
+            .eq(puzzles.iter().map(|puzzle| puzzle.day)));
+        assert!(outcomes.iter().all(|outcome| outcome.answers.is_err()));
+    }
+
+    #[test]
+    fn more_jobs_than_puzzles() {
+        let puzzles = crate::registry::find(2015, 2)
+            .into_iter()
+            .collect::<Vec<_>>();
+        let outcomes = solve_all(&puzzles, 8, |_| Ok("2x3x4".to_owned()));
+        assert_eq!(
+            outcomes[0]
+                .answers
+                .as_ref()
+                .unwrap()
+                .part1
+                .as_ref()
+                .unwrap(),
+            "58"
+        );
+    }
+
+    #[test]
+    fn panicking_day_fails_alone() {
+        let puzzles = &crate::registry::of_year(2015)[..3];
+        let outcomes = solve_all(puzzles, 2, |puzzle| match puzzle.day {
+            2 => panic!("boom"),
+            _ => Ok(String::new()),
+        });
+        assert_eq!(outcomes.len(), 3);
+        assert_eq!(
+            outcomes[1].answers.as_ref().unwrap_err().to_string(),
+            "[2015 day 2] Compute error: panicked: boom"
+        );
+        assert!(outcomes[0].answers.is_ok());
+        assert!(outcomes[2].answers.is_ok());
+    }
+}
@@ -0,0 +1,137 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_RS: &str = include_str!("../templates/main.rs.tmpl");
+
+#[derive(Debug, thiserror::Error)]
+pub enum ScaffoldError {
+    #[error("{} is not a workspace root", .0.display())]
+    NotWorkspace(PathBuf),
+    #[error("{} already exists", .0.display())]
+    AlreadyExists(PathBuf),
+    #[error("Can't write {}: {1}", .0.display())]
+    Io(PathBuf, #[source] io::Error),
+}
+
+/// Generates crate `aoc-<year>/aoc-<year>-day<day>` in workspace at `root`,
+/// together with an empty input file, unless one exists already.
+///
+/// Returns paths of created files.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if !is_workspace(root) {
+        return Err(ScaffoldError::NotWorkspace(root.to_owned()));
+    }
+    let crate_dir = root.join(format!("aoc-{year}/aoc-{year}-day{day}"));
+    if crate_dir.exists() {
+        return Err(ScaffoldError::AlreadyExists(crate_dir));
+    }
+
+    let render = |template: &str| {
+        template
+            .replace("{{YEAR}}", &year.to_string())
+            .replace("{{DAY}}", &day.to_string())
+    };
+    let mut files = vec![
+        (crate_dir.join("Cargo.toml"), render(CARGO_TOML)),
+        (crate_dir.join("src/lib.rs"), render(LIB_RS)),
+        (crate_dir.join("src/main.rs"), render(MAIN_RS)),
+    ];
+    let input = root.join(helpers::input::default_path(year, day));
+    if !input.exists() {
+        files.push((input, String::new()));
+    }
+
+    for (path, contents) in &files {
+        write(path, contents).map_err(|e| ScaffoldError::Io(path.clone(), e))?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Whether `root` has a manifest with a `[workspace]` table.
+fn is_workspace(root: &Path) -> bool {
+    std::fs::read_to_string(root.join("Cargo.toml"))
+        .ok()
+        .and_then(|manifest| manifest.parse::<toml::Table>().ok())
+        .is_some_and(|manifest| manifest.get("workspace").is_some_and(toml::Value::is_table))
+}
+
+fn write(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join("Cargo.toml"), "[workspace]\n").unwrap();
+        root
+    }
+
+    #[test]
+    fn generates_crate() {
+        let root = workspace();
+        let files = new_day(root.path(), 2016, 3).unwrap();
+        assert_eq!(files.len(), 4);
+
+        let crate_dir = root.path().join("aoc-2016/aoc-2016-day3");
+        let manifest = std::fs::read_to_string(crate_dir.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains(r#"name = "aoc-2016-day3""#));
+        let lib = std::fs::read_to_string(crate_dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day3"));
+        assert!(lib.contains("const YEAR: u16 = 2016;"));
+        assert!(!lib.contains("{{"));
+        let main = std::fs::read_to_string(crate_dir.join("src/main.rs")).unwrap();
+        assert!(main.contains("use aoc_2016_day3::Day3;"));
+        assert!(root
+            .path()
+            .join("aoc-2016/input/aoc-2016-day3.txt")
+            .is_file());
+    }
+
+    #[test]
+    fn keeps_existing_input() {
+        let root = workspace();
+        let input = root.path().join("aoc-2016/input/aoc-2016-day3.txt");
+        write(&input, "puzzle input").unwrap();
+
+        let files = new_day(root.path(), 2016, 3).unwrap();
+        assert!(!files.contains(&input));
+        assert_eq!(std::fs::read_to_string(input).unwrap(), "puzzle input");
+    }
+
+    #[test]
+    fn refuses_existing_crate() {
+        let root = workspace();
+        new_day(root.path(), 2016, 3).unwrap();
+        assert!(matches!(
+            new_day(root.path(), 2016, 3),
+            Err(ScaffoldError::AlreadyExists(_))
+        ));
+    }
+
+    #[test]
+    fn needs_workspace() {
+        let root = tempfile::tempdir().unwrap();
+        assert!(matches!(
+            new_day(root.path(), 2016, 3),
+            Err(ScaffoldError::NotWorkspace(_))
+        ));
+
+        let manifest = "[package]\nname = \"aoc\"\nversion = \"0.1.0\"\n";
+        std::fs::write(root.path().join("Cargo.toml"), manifest).unwrap();
+        assert!(matches!(
+            new_day(root.path(), 2016, 3),
+            Err(ScaffoldError::NotWorkspace(_))
+        ));
+        assert!(!root.path().join("aoc-2016").exists());
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt::Display;
+
+use crate::input::InputError;
+
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "part 1"),
+            Part::Two => write!(f, "part 2"),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ErrorKind {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("{0}")]
+    Input(#[from] InputError),
+    #[error("Parse error: {0}")]
+    Parse(#[source] BoxError),
+    #[error("Compute error: {0}")]
+    Compute(#[source] BoxError),
+}
+
+/// Error, shared by all solutions.
+/// Besides the failure itself, it keeps where it happened: day, part and input line,
+/// as much as is known.
+#[derive(Debug, thiserror::Error)]
+pub struct AocError {
+    #[source]
+    pub kind: ErrorKind,
+    pub day: Option<(u16, u8)>,
+    pub part: Option<Part>,
+    pub line: Option<usize>,
+}
+
+impl AocError {
+    pub fn new(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            day: None,
+            part: None,
+            line: None,
+        }
+    }
+
+    pub fn parse(e: impl Into<BoxError>) -> Self {
+        Self::new(ErrorKind::Parse(e.into()))
+    }
+
+    pub fn compute(e: impl Into<BoxError>) -> Self {
+        Self::new(ErrorKind::Compute(e.into()))
+    }
+
+    /// Line numbers start from 1.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn in_day(mut self, year: u16, day: u8) -> Self {
+        self.day = Some((year, day));
+        self
+    }
+
+    pub fn in_part(mut self, part: Part) -> Self {
+        self.part = Some(part);
+        self
+    }
+}
+
+impl From<std::io::Error> for AocError {
+    fn from(e: std::io::Error) -> Self {
+        Self::new(ErrorKind::Io(e))
+    }
+}
+
+impl From<InputError> for AocError {
+    fn from(e: InputError) -> Self {
+        Self::new(ErrorKind::Input(e))
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let context = [
+            self.day.map(|(year, day)| format!("{year} day {day}")),
+            self.part.map(|part| part.to_string()),
+            self.line.map(|line| format!("line {line}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        if !context.is_empty() {
+            write!(f, "[{}] ", context.join(", "))?;
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+/// Parses every line of `input` with `f`.
+/// On failure, the error remembers the number of the line.
+pub fn parse_lines<T, E, F>(input: &str, mut f: F) -> Result<Vec<T>, AocError>
+where
+    F: FnMut(&str) -> Result<T, E>,
+    E: Into<BoxError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| AocError::parse(e).at_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_without_context() {
+        let e = AocError::compute("no way");
+        assert_eq!(e.to_string(), "Compute error: no way");
+    }
+
+    #[test]
+    fn display_with_context() {
+        let e = AocError::parse("bad number")
+            .at_line(3)
+            .in_part(Part::Two)
+            .in_day(2015, 7);
+        assert_eq!(
+            e.to_string(),
+            "[2015 day 7, part 2, line 3] Parse error: bad number"
+        );
+    }
+
+    #[test]
+    fn parse_lines_works() {
+        let got = parse_lines("1\n2\n3", str::parse::<u32>).unwrap();
+        assert_eq!(got, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn parse_lines_remembers_line() {
+        let e = parse_lines("1\n2\nthree\n4", str::parse::<u32>).unwrap_err();
+        assert_eq!(e.line, Some(3));
+        assert!(matches!(e.kind, ErrorKind::Parse(_)));
+    }
+}
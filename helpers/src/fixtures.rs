@@ -0,0 +1,210 @@
+//! Puzzle examples, kept as data files.
+//!
+//! Examples of a day live in `aoc-<year>/examples/day<day>/<name>.txt`.
+//! Each file starts with expected answers, followed by `---` line and the example input:
+//! ```text
+//! part1: 58
+//! part2: 34
+//! ---
+//! 2x3x4
+//! ```
+//! Either part may be left out, if the example doesn't cover it.
+//!
+//! Tests are generated by [`example_tests!`](crate::example_tests).
+
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{input::search_paths, Part, Solution};
+
+const SEPARATOR: &str = "---";
+
+#[derive(Debug, thiserror::Error)]
+pub enum FixtureError {
+    #[error("No `{SEPARATOR}` line between answers and input")]
+    NoSeparator,
+    #[error("Invalid answer line '{0}', expected `part1: <answer>` or `part2: <answer>`")]
+    InvalidAnswer(String),
+    #[error("Can't read example {}: {1}", .0.display())]
+    Io(PathBuf, #[source] std::io::Error),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Fixture {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub input: String,
+}
+
+impl FromStr for Fixture {
+    type Err = FixtureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (answers, input) = match s.split_once(&format!("\n{SEPARATOR}\n")) {
+            Some(split) => split,
+            None => s
+                .strip_prefix(&format!("{SEPARATOR}\n"))
+                .map(|input| ("", input))
+                .ok_or(FixtureError::NoSeparator)?,
+        };
+
+        let mut fixture = Fixture {
+            input: input.to_owned(),
+            ..Fixture::default()
+        };
+        for line in answers.lines().filter(|line| !line.trim().is_empty()) {
+            let invalid = || FixtureError::InvalidAnswer(line.to_owned());
+            let (part, answer) = line.split_once(':').ok_or_else(invalid)?;
+            let answer = Some(answer.trim().to_owned());
+            match part.trim() {
+                "part1" => fixture.part1 = answer,
+                "part2" => fixture.part2 = answer,
+                _ => return Err(invalid()),
+            }
+        }
+        Ok(fixture)
+    }
+}
+
+impl Fixture {
+    pub fn load(path: &Path) -> Result<Self, FixtureError> {
+        std::fs::read_to_string(path)
+            .map_err(|e| FixtureError::Io(path.to_owned(), e))?
+            .parse()
+    }
+
+    /// Loads example `name` of the day from the conventional location.
+    pub fn of_day(year: u16, day: u8, name: &str) -> Result<Self, FixtureError> {
+        let path = examples_dir(year, day).join(format!("{name}.txt"));
+        let path = search_paths(&path)
+            .into_iter()
+            .find(|path| path.is_file())
+            .unwrap_or(path);
+        Self::load(&path)
+    }
+
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Examples directory of the day, relative to the workspace root.
+pub fn examples_dir(year: u16, day: u8) -> PathBuf {
+    format!("aoc-{year}/examples/day{day}").into()
+}
+
+/// Checks example `name` of the day, answering each part with `solve`.
+///
+/// Panics on any mismatch, so it's meant to be used from tests.
+pub fn check_example_with<A: ToString>(
+    year: u16,
+    day: u8,
+    name: &str,
+    solve: impl Fn(&str, Part) -> A,
+) {
+    let fixture = Fixture::of_day(year, day, name)
+        .unwrap_or_else(|e| panic!("{year} day {day} example '{name}': {e}"));
+    assert!(
+        fixture.part1.is_some() || fixture.part2.is_some(),
+        "{year} day {day} example '{name}' has no answers"
+    );
+    for part in [Part::One, Part::Two] {
+        if let Some(expected) = fixture.expected(part) {
+            let got = solve(&fixture.input, part).to_string();
+            assert_eq!(got, expected, "{year} day {day} example '{name}', {part}");
+        }
+    }
+}
+
+/// Checks example `name` of `S`'s day with the solution itself.
+pub fn check_example<S: Solution>(name: &str) {
+    check_example_with(S::YEAR, S::DAY, name, |input, part| {
+        let input = S::parse(input).unwrap_or_else(|e| panic!("example '{name}': {e}"));
+        let answer = match part {
+            Part::One => S::part1(&input).map(|answer| answer.to_string()),
+            Part::Two => S::part2(&input).map(|answer| answer.to_string()),
+        };
+        answer.unwrap_or_else(|e| panic!("example '{name}', {part}: {e}"))
+    });
+}
+
+/// Generates a `#[test]` for every listed example of the day.
+///
+/// Examples are checked by the solution itself:
+/// ```ignore
+/// helpers::example_tests!(Day2; one_box, long_box);
+/// ```
+/// or by a custom `fn(&str, Part) -> impl ToString`,
+/// when an example differs from the puzzle, e.g. makes fewer steps:
+/// ```ignore
+/// helpers::example_tests!(2015, 18, four_steps; example);
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty; $($name:ident),+ $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                $crate::fixtures::check_example::<$solution>(stringify!($name));
+            }
+        )+
+    };
+    ($year:expr, $day:expr, $solve:expr; $($name:ident),+ $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                $crate::fixtures::check_example_with($year, $day, stringify!($name), $solve);
+            }
+        )+
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_fixture() {
+        let fixture: Fixture = "part1: 58\npart2: 34\n---\n2x3x4\n".parse().unwrap();
+        assert_eq!(fixture.part1.as_deref(), Some("58"));
+        assert_eq!(fixture.part2.as_deref(), Some("34"));
+        assert_eq!(fixture.input, "2x3x4\n");
+    }
+
+    #[test]
+    fn parse_partial_fixture() {
+        let fixture: Fixture = "part2: x\n---\nabc".parse().unwrap();
+        assert_eq!(fixture.part1, None);
+        assert_eq!(fixture.expected(Part::Two), Some("x"));
+        assert_eq!(fixture.input, "abc");
+    }
+
+    #[test]
+    fn parse_invalid_fixture() {
+        assert!(matches!(
+            "part1: 1\n2x3x4".parse::<Fixture>(),
+            Err(FixtureError::NoSeparator)
+        ));
+        assert!(matches!(
+            "part3: 1\n---\n2x3x4".parse::<Fixture>(),
+            Err(FixtureError::InvalidAnswer(_))
+        ));
+    }
+
+    #[test]
+    fn load_fixture() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("example.txt");
+        std::fs::write(&path, "part1: 3\n---\nabc").unwrap();
+        assert_eq!(Fixture::load(&path).unwrap().input, "abc");
+        assert!(matches!(
+            Fixture::load(&dir.path().join("missing.txt")),
+            Err(FixtureError::Io(..))
+        ));
+    }
+}
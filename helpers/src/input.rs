@@ -0,0 +1,244 @@
+//! Puzzle input loading.
+//!
+//! Input is taken from one of:
+//! - `-` — standard input;
+//! - an explicit file path;
+//! - nothing — the conventional `aoc-<year>/input/aoc-<year>-day<day>.txt`,
+//!   looked up in the current directory and then in the workspace root.
+//!
+//! Missing inputs may be fetched with a [`Fetcher`].
+
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use crate::Solution;
+
+mod fetch;
+
+#[cfg(feature = "http")]
+pub use fetch::HttpFetcher;
+pub use fetch::{CachedFetcher, FetchError, Fetcher};
+
+/// Workspace root, so conventional inputs are found wherever binaries are run from.
+const WORKSPACE_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+#[derive(Debug, thiserror::Error)]
+pub enum InputError {
+    #[error("Input for {year} day {day} not found, tried {}", display_paths(.tried))]
+    NotFound {
+        year: u16,
+        day: u8,
+        tried: Vec<PathBuf>,
+    },
+    #[error("Can't read input {}: {source}", .path.display())]
+    File {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Can't read input from stdin: {0}")]
+    Stdin(#[source] std::io::Error),
+    #[error(transparent)]
+    Fetch(#[from] FetchError),
+}
+
+fn display_paths(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+    /// Conventional location of the puzzle input.
+    Default {
+        year: u16,
+        day: u8,
+    },
+}
+
+impl InputSource {
+    /// `arg` is what user has given: `-`, a path or nothing.
+    pub fn new(arg: Option<&Path>, year: u16, day: u8) -> Self {
+        match arg {
+            Some(arg) if arg == Path::new("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path.to_owned()),
+            None => InputSource::Default { year, day },
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+            InputSource::File(path) => read_file(path),
+            &InputSource::Default { year, day } => read_file(&find_default(year, day)?),
+        }
+    }
+
+    /// Opens the input to be streamed, for inputs too long to be read whole.
+    pub fn open(&self) -> Result<Box<dyn Read>, InputError> {
+        let path = match self {
+            InputSource::Stdin => return Ok(Box::new(std::io::stdin().lock())),
+            InputSource::File(path) => path.clone(),
+            &InputSource::Default { year, day } => find_default(year, day)?,
+        };
+        match std::fs::File::open(&path) {
+            Ok(file) => Ok(Box::new(file)),
+            Err(source) => Err(InputError::File { path, source }),
+        }
+    }
+
+    /// Like [`InputSource::read`], but the conventional input is fetched, if it's not found.
+    pub fn read_or_fetch(&self, fetcher: &impl Fetcher) -> Result<String, InputError> {
+        match (self, self.read()) {
+            (&InputSource::Default { year, day }, Err(InputError::NotFound { .. })) => {
+                Ok(fetcher.fetch(year, day)?)
+            }
+            (_, res) => res,
+        }
+    }
+}
+
+fn find_default(year: u16, day: u8) -> Result<PathBuf, InputError> {
+    let tried = default_paths(year, day);
+    match tried.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(InputError::NotFound { year, day, tried }),
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|source| InputError::File {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+/// Conventional input path, relative to the workspace root.
+pub fn default_path(year: u16, day: u8) -> PathBuf {
+    format!("aoc-{year}/input/aoc-{year}-day{day}.txt").into()
+}
+
+fn default_paths(year: u16, day: u8) -> Vec<PathBuf> {
+    search_paths(&default_path(year, day))
+}
+
+/// Root of the workspace, where conventional inputs are kept.
+pub fn workspace_root() -> &'static Path {
+    Path::new(WORKSPACE_ROOT)
+}
+
+/// Where a workspace-relative `path` is looked for: current directory first, then workspace root.
+pub fn search_paths(path: &Path) -> Vec<PathBuf> {
+    vec![path.to_owned(), Path::new(WORKSPACE_ROOT).join(path)]
+}
+
+/// Reads input of `S`, choosing the source by the first command line argument.
+pub fn from_args<S: Solution>() -> Result<String, InputError> {
+    source_from_args::<S>().read()
+}
+
+/// Like [`from_args`], but opens the input to be streamed.
+pub fn open_from_args<S: Solution>() -> Result<Box<dyn Read>, InputError> {
+    source_from_args::<S>().open()
+}
+
+fn source_from_args<S: Solution>() -> InputSource {
+    let arg = std::env::args_os().nth(1).map(PathBuf::from);
+    InputSource::new(arg.as_deref(), S::YEAR, S::DAY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_from_arg() {
+        assert_eq!(
+            InputSource::new(Some(Path::new("-")), 2015, 1),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::new(Some(Path::new("in.txt")), 2015, 1),
+            InputSource::File("in.txt".into())
+        );
+        assert_eq!(
+            InputSource::new(None, 2015, 1),
+            InputSource::Default { year: 2015, day: 1 }
+        );
+    }
+
+    #[test]
+    fn default_path_convention() {
+        assert_eq!(
+            default_path(2015, 7),
+            PathBuf::from("aoc-2015/input/aoc-2015-day7.txt")
+        );
+    }
+
+    #[test]
+    fn default_found_from_anywhere() {
+        let input = InputSource::new(None, 2015, 2).read().unwrap();
+        assert!(!input.is_empty());
+    }
+
+    #[test]
+    fn missing_file_is_error() {
+        let err = InputSource::new(Some(Path::new("no/such/input.txt")), 2015, 1)
+            .read()
+            .unwrap_err();
+        assert!(matches!(err, InputError::File { .. }));
+    }
+
+    #[test]
+    fn open_streams_same_input() {
+        let source = InputSource::new(None, 2015, 2);
+        let mut streamed = String::new();
+        source
+            .open()
+            .unwrap()
+            .read_to_string(&mut streamed)
+            .unwrap();
+        assert_eq!(streamed, source.read().unwrap());
+
+        let missing = InputSource::new(Some(Path::new("no/such/input.txt")), 2015, 1);
+        assert!(matches!(missing.open(), Err(InputError::File { .. })));
+    }
+
+    #[test]
+    fn missing_default_is_error() {
+        let err = InputSource::new(None, 1999, 25).read().unwrap_err();
+        let InputError::NotFound { tried, .. } = err else {
+            panic!("unexpected error {err:?}");
+        };
+        assert_eq!(tried.len(), 2);
+    }
+
+    #[test]
+    fn fetch_only_missing_default() {
+        let fetcher = |_, _| Ok("fetched".to_owned());
+        let missing = InputSource::new(None, 1999, 25);
+        assert_eq!(missing.read_or_fetch(&fetcher).unwrap(), "fetched");
+
+        let present = InputSource::new(None, 2015, 2);
+        assert_ne!(present.read_or_fetch(&fetcher).unwrap(), "fetched");
+
+        let explicit = InputSource::new(Some(Path::new("no/such/input.txt")), 1999, 25);
+        assert!(matches!(
+            explicit.read_or_fetch(&fetcher),
+            Err(InputError::File { .. })
+        ));
+    }
+}
@@ -0,0 +1,259 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use super::default_path;
+use crate::BoxError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum FetchError {
+    #[error("Can't fetch input for {year} day {day}: {source}")]
+    Fetch {
+        year: u16,
+        day: u8,
+        #[source]
+        source: BoxError,
+    },
+    #[error("Can't access cached input {}: {1}", .0.display())]
+    Cache(PathBuf, #[source] io::Error),
+    #[error("No session cookie, set {0} environment variable")]
+    NoSession(&'static str),
+}
+
+/// Source of puzzle inputs, e.g. the Advent of Code site.
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError>;
+}
+
+impl<F> Fetcher for F
+where
+    F: Fn(u16, u8) -> Result<String, FetchError>,
+{
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        self(year, day)
+    }
+}
+
+/// Keeps inputs, fetched by another [`Fetcher`], on the filesystem,
+/// so every input is fetched only once.
+///
+/// Inputs are stored in the conventional layout under `root`:
+/// `<root>/aoc-<year>/input/aoc-<year>-day<day>.txt`.
+/// Empty files are placeholders, not inputs, so they are fetched over.
+#[derive(Debug, Clone)]
+pub struct CachedFetcher<F> {
+    root: PathBuf,
+    fetcher: F,
+}
+
+impl<F> CachedFetcher<F> {
+    pub fn new(root: impl Into<PathBuf>, fetcher: F) -> Self {
+        Self {
+            root: root.into(),
+            fetcher,
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.root.join(default_path(year, day))
+    }
+}
+
+impl<F: Fetcher> Fetcher for CachedFetcher<F> {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let path = self.path(year, day);
+        match std::fs::read_to_string(&path) {
+            Ok(input) if !input.is_empty() => return Ok(input),
+            Ok(_) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(FetchError::Cache(path, e)),
+        }
+
+        let input = self.fetcher.fetch(year, day)?;
+        store(&path, &input).map_err(|e| FetchError::Cache(path, e))?;
+        Ok(input)
+    }
+}
+
+/// Writes to a temporary file first, so an interrupted write doesn't leave a broken input.
+fn store(path: &Path, input: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("txt.part");
+    std::fs::write(&tmp, input)?;
+    std::fs::rename(tmp, path)
+}
+
+/// Fetches inputs from the Advent of Code site, authenticated by the session cookie.
+#[cfg(feature = "http")]
+#[derive(Debug, Clone)]
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+}
+
+#[cfg(feature = "http")]
+impl HttpFetcher {
+    pub const AOC_URL: &'static str = "https://adventofcode.com";
+    pub const SESSION_VAR: &'static str = "AOC_SESSION";
+
+    pub fn new(session: impl Into<String>) -> Self {
+        Self {
+            base_url: Self::AOC_URL.to_owned(),
+            session: session.into(),
+        }
+    }
+
+    /// Takes the session cookie from `AOC_SESSION` environment variable.
+    pub fn from_env() -> Result<Self, FetchError> {
+        std::env::var(Self::SESSION_VAR)
+            .map(Self::new)
+            .map_err(|_| FetchError::NoSession(Self::SESSION_VAR))
+    }
+
+    /// Fetches from another server, e.g. a local stand-in in tests.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let fetch_error = |source: BoxError| FetchError::Fetch { year, day, source };
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set(
+                "User-Agent",
+                concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+            )
+            .call()
+            .map_err(|e| fetch_error(e.into()))?
+            .into_string()
+            .map_err(|e| fetch_error(e.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn cache_fetches_once() {
+        let root = tempfile::tempdir().unwrap();
+        let calls = Cell::new(0);
+        let fetcher = CachedFetcher::new(root.path(), |year, day| {
+            calls.set(calls.get() + 1);
+            Ok(format!("{year}-{day}"))
+        });
+
+        assert_eq!(fetcher.fetch(2015, 1).unwrap(), "2015-1");
+        assert_eq!(fetcher.fetch(2015, 1).unwrap(), "2015-1");
+        assert_eq!(fetcher.fetch(2015, 2).unwrap(), "2015-2");
+        assert_eq!(calls.get(), 2);
+        assert_eq!(
+            std::fs::read_to_string(root.path().join("aoc-2015/input/aoc-2015-day2.txt")).unwrap(),
+            "2015-2"
+        );
+    }
+
+    #[test]
+    fn cache_fills_placeholder() {
+        let root = tempfile::tempdir().unwrap();
+        let fetcher = CachedFetcher::new(root.path(), |_, _| Ok("input".to_owned()));
+        store(&fetcher.path(2015, 1), "").unwrap();
+        assert_eq!(fetcher.fetch(2015, 1).unwrap(), "input");
+    }
+
+    #[test]
+    fn failed_fetch_is_not_cached() {
+        let root = tempfile::tempdir().unwrap();
+        let fetcher = CachedFetcher::new(root.path(), |year, day| {
+            Err(FetchError::Fetch {
+                year,
+                day,
+                source: "offline".into(),
+            })
+        });
+        assert!(fetcher.fetch(2015, 1).is_err());
+        assert!(!fetcher.path(2015, 1).exists());
+    }
+
+    #[cfg(feature = "http")]
+    mod http {
+        use std::{
+            io::{BufRead, BufReader, Write},
+            net::TcpListener,
+            thread::JoinHandle,
+        };
+
+        use super::*;
+
+        /// Stand-in for the Advent of Code site, answering a single request.
+        /// Returns its url and the request line with the cookie header it has got.
+        fn serve_once(status: &str, body: &str) -> (String, JoinHandle<(String, String)>) {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let response = format!(
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let server = std::thread::spawn(move || {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = BufReader::new(&stream)
+                    .lines()
+                    .map(Result::unwrap)
+                    .take_while(|line| !line.is_empty());
+                let request_line = request.next().unwrap();
+                let cookie = request
+                    .find(|line| line.to_lowercase().starts_with("cookie:"))
+                    .unwrap_or_default();
+                stream.write_all(response.as_bytes()).unwrap();
+                (request_line, cookie)
+            });
+            (url, server)
+        }
+
+        #[test]
+        fn fetches_with_session() {
+            let (url, server) = serve_once("200 OK", "1x2x3\n");
+            let fetcher = HttpFetcher::new("secret").with_base_url(url);
+
+            assert_eq!(fetcher.fetch(2015, 2).unwrap(), "1x2x3\n");
+            let (request_line, cookie) = server.join().unwrap();
+            assert_eq!(request_line, "GET /2015/day/2/input HTTP/1.1");
+            assert_eq!(cookie.to_lowercase(), "cookie: session=secret");
+        }
+
+        #[test]
+        fn http_error() {
+            let (url, server) = serve_once("400 Bad Request", "Please log in");
+            let fetcher = HttpFetcher::new("expired").with_base_url(url);
+
+            assert!(matches!(
+                fetcher.fetch(2015, 2),
+                Err(FetchError::Fetch { .. })
+            ));
+            server.join().unwrap();
+        }
+
+        #[test]
+        fn cached_http_fetches_once() {
+            let root = tempfile::tempdir().unwrap();
+            let (url, server) = serve_once("200 OK", "()())");
+            let fetcher =
+                CachedFetcher::new(root.path(), HttpFetcher::new("secret").with_base_url(url));
+
+            assert_eq!(fetcher.fetch(2015, 1).unwrap(), "()())");
+            server.join().unwrap();
+            // The server is gone, so this one can only come from the cache.
+            assert_eq!(fetcher.fetch(2015, 1).unwrap(), "()())");
+        }
+    }
+}
@@ -1,9 +1,7 @@
-use std::path::PathBuf;
+mod error;
+pub mod fixtures;
+pub mod input;
+mod solution;
 
-pub fn get_filepath_from_args() -> PathBuf {
-    std::env::args()
-        .skip(1)
-        .next()
-        .expect("Provide a file path as first argument")
-        .into()
-}
+pub use error::{parse_lines, AocError, BoxError, ErrorKind, Part};
+pub use solution::{Answers, Puzzle, Solution, Timings};
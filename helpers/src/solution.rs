@@ -0,0 +1,156 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::error::{AocError, Part};
+
+/// Solution for a single day of some year.
+///
+/// Input is parsed once and then shared by both parts.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, AocError>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, AocError>;
+}
+
+/// Answers of both parts of a [`Puzzle`].
+/// Parts fail independently of each other.
+#[derive(Debug)]
+pub struct Answers {
+    pub part1: Result<String, AocError>,
+    pub part2: Result<String, AocError>,
+    pub timings: Timings,
+}
+
+/// Wall time, spent on each step of a [`Puzzle`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+/// Type-erased [`Solution`], so solutions for different days can be stored together.
+#[derive(Debug, Clone, Copy)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    solve: fn(&str) -> Result<Answers, AocError>,
+}
+
+impl Puzzle {
+    pub fn of<S: Solution>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            solve: solve::<S>,
+        }
+    }
+
+    /// Fails only if the input can't be parsed.
+    pub fn solve(&self, input: &str) -> Result<Answers, AocError> {
+        (self.solve)(input)
+    }
+}
+
+fn solve<S: Solution>(input: &str) -> Result<Answers, AocError> {
+    let in_context = |e: AocError| e.in_day(S::YEAR, S::DAY);
+    let (input, parse) = timed(|| S::parse(input));
+    let input = input.map_err(in_context)?;
+    let (part1, part1_time) = timed(|| S::part1(&input));
+    let (part2, part2_time) = timed(|| S::part2(&input));
+    Ok(Answers {
+        part1: part1
+            .map(|answer| answer.to_string())
+            .map_err(|e| in_context(e).in_part(Part::One)),
+        part2: part2
+            .map(|answer| answer.to_string())
+            .map_err(|e| in_context(e).in_part(Part::Two)),
+        timings: Timings {
+            parse,
+            part1: part1_time,
+            part2: part2_time,
+        },
+    })
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let res = f();
+    (res, start.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::ErrorKind, parse_lines};
+
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 1;
+
+        type Input = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input, AocError> {
+            parse_lines(input, str::parse)
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Answer1, AocError> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Self::Answer2, AocError> {
+            input
+                .iter()
+                .try_fold(1u32, |acc, &x| acc.checked_mul(x))
+                .ok_or_else(|| AocError::compute("overflow"))
+        }
+    }
+
+    #[test]
+    fn puzzle_of_solution() {
+        let puzzle = Puzzle::of::<Sum>();
+        assert_eq!((puzzle.year, puzzle.day), (2015, 1));
+        let answers = puzzle.solve("2\n3\n4").unwrap();
+        assert_eq!(answers.part1.unwrap(), "9");
+        assert_eq!(answers.part2.unwrap(), "24");
+        assert_eq!(
+            answers.timings.total(),
+            answers.timings.parse + answers.timings.part1 + answers.timings.part2
+        );
+    }
+
+    #[test]
+    fn parse_error_has_context() {
+        let e = Puzzle::of::<Sum>().solve("2\nx").unwrap_err();
+        assert_eq!((e.day, e.part, e.line), (Some((2015, 1)), None, Some(2)));
+    }
+
+    #[test]
+    fn parts_fail_independently() {
+        let answers = Puzzle::of::<Sum>().solve("65536\n65536").unwrap();
+        assert_eq!(answers.part1.unwrap(), "131072");
+        let e = answers.part2.unwrap_err();
+        assert_eq!(e.part, Some(Part::Two));
+        assert!(matches!(e.kind, ErrorKind::Compute(_)));
+    }
+}